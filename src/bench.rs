@@ -0,0 +1,130 @@
+use std::{fs, path::Path, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::RunError,
+    solution::{Day, Part},
+};
+
+/// How much slower a stage's median has to get before it counts as a
+/// regression, on top of the relative threshold. Anything quicker than this is
+/// mostly measuring noise.
+pub const NOISE: Duration = Duration::from_micros(50);
+
+/// The spread of one stage's timings over every run, in nanoseconds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: u64,
+    pub median: u64,
+    pub max: u64,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut nanos: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        nanos.sort_unstable();
+        Stats {
+            min: nanos[0],
+            median: nanos[nanos.len() / 2],
+            max: nanos[nanos.len() - 1],
+        }
+    }
+}
+
+/// Timings for parsing a day's input and solving each part
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timing {
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl Timing {
+    pub fn stages(&self) -> [(&'static str, Stats); 3] {
+        [
+            ("parse", self.parse),
+            ("part 1", self.part1),
+            ("part 2", self.part2),
+        ]
+    }
+}
+
+/// A whole benchmark run, as written to and read back from JSON
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    /// The input name every day was run on, e.g. `""` or `"ex"`
+    pub input: String,
+    pub runs: usize,
+    pub timings: Vec<Timing>,
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let json = fs::read_to_string(path)
+            .map_err(|e| format!("couldn't read {}: {e}", path.display()))?;
+        serde_json::from_str(&json)
+            .map_err(|e| format!("{} isn't a benchmark: {e}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).unwrap();
+        fs::write(path, json + "\n").map_err(|e| format!("couldn't write {}: {e}", path.display()))
+    }
+}
+
+/// Runs a day on the same input `runs` times over
+pub fn bench(day: &Day, input: &str, runs: usize) -> Result<Timing, RunError> {
+    let mut samples = [vec![], vec![], vec![]];
+    for _ in 0..runs.max(1) {
+        let run = day.run(input, &Part::BOTH)?;
+        samples[0].push(run.parse);
+        for answer in run.answers {
+            samples[answer.part.number() as usize].push(answer.elapsed);
+        }
+    }
+    Ok(Timing {
+        day: day.number,
+        parse: Stats::new(&samples[0]),
+        part1: Stats::new(&samples[1]),
+        part2: Stats::new(&samples[2]),
+    })
+}
+
+/// A stage whose median got slower between two reports
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regression {
+    pub day: u8,
+    pub stage: &'static str,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+/// Every stage that's at least `threshold` (e.g. `0.1` for 10%) and [`NOISE`]
+/// slower than in the baseline. Days missing from either report are skipped.
+pub fn compare(baseline: &Report, current: &Report, threshold: f64) -> Vec<Regression> {
+    let mut regressions = Vec::new();
+    for timing in &current.timings {
+        let before = match baseline.timings.iter().find(|t| t.day == timing.day) {
+            Some(before) => before,
+            None => continue,
+        };
+        for ((stage, old), (_, new)) in before.stages().into_iter().zip(timing.stages()) {
+            let (before, after) = (
+                Duration::from_nanos(old.median),
+                Duration::from_nanos(new.median),
+            );
+            let slower = after.saturating_sub(before);
+            if slower > NOISE && slower.as_secs_f64() > before.as_secs_f64() * threshold {
+                regressions.push(Regression {
+                    day: timing.day,
+                    stage,
+                    before,
+                    after,
+                });
+            }
+        }
+    }
+    regressions
+}
@@ -0,0 +1,256 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+    io::BufRead,
+};
+
+use crate::{
+    error::{InputError, ParseError, ReadError},
+    parse::{self, Line},
+    solution::{Solution, SolveError},
+};
+
+/// One elf's food, in the order the elves were listed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    /// 0-based position in the input
+    pub index: usize,
+    pub items: usize,
+    pub calories: u32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Inventory {
+    pub elves: Vec<Elf>,
+}
+
+impl Inventory {
+    /// Builds an inventory from each elf's list of items. Elves carrying
+    /// nothing are kept, with no items and no calories.
+    pub fn from_groups<G: IntoIterator<Item = u32>>(groups: impl IntoIterator<Item = G>) -> Self {
+        let elves = groups
+            .into_iter()
+            .enumerate()
+            .map(|(index, items)| {
+                let (items, calories) = items
+                    .into_iter()
+                    .fold((0, 0), |(n, total), item| (n + 1, total + item));
+                Elf {
+                    index,
+                    items,
+                    calories,
+                }
+            })
+            .collect();
+        Inventory { elves }
+    }
+
+    /// Every elf, most calories first. Elves carrying the same amount keep
+    /// their input order.
+    pub fn ranked(&self) -> Vec<&Elf> {
+        let mut ranked: Vec<_> = self.elves.iter().collect();
+        ranked.sort_by_key(|elf| (Reverse(elf.calories), elf.index));
+        ranked
+    }
+
+    /// The `n` elves carrying the most calories, or all of them if there are
+    /// fewer than `n`
+    pub fn top(&self, n: usize) -> Vec<&Elf> {
+        let mut ranked = self.ranked();
+        ranked.truncate(n);
+        ranked
+    }
+
+    /// Like [`Inventory::top`], but also takes any elves tied with the last one
+    pub fn top_with_ties(&self, n: usize) -> Vec<&Elf> {
+        let ranked = self.ranked();
+        let cutoff = match n.checked_sub(1).and_then(|last| ranked.get(last)) {
+            Some(last) => last.calories,
+            None => return ranked.into_iter().take(n).collect(),
+        };
+        ranked
+            .into_iter()
+            .enumerate()
+            .take_while(|&(i, elf)| i < n || elf.calories == cutoff)
+            .map(|(_, elf)| elf)
+            .collect()
+    }
+
+    /// Combined calories of the top `n` elves
+    pub fn top_total(&self, n: usize) -> u32 {
+        self.top(n).iter().map(|elf| elf.calories).sum()
+    }
+
+    /// A table of the top `n` elves (and anyone tied with the last of them),
+    /// numbering elves from 1 as they appear in the input. Tied ranks share a
+    /// number.
+    pub fn report(&self, n: usize) -> String {
+        let top = self.top_with_ties(n);
+        let mut lines = vec![format!(
+            "{:>4}  {:>5}  {:>5}  {:>8}",
+            "Rank", "Elf", "Items", "Calories"
+        )];
+        let mut rank = 0;
+        for (i, elf) in top.iter().enumerate() {
+            if i == 0 || top[i - 1].calories != elf.calories {
+                rank = i + 1;
+            }
+            lines.push(format!(
+                "{rank:>4}  {:>5}  {:>5}  {:>8}",
+                elf.index + 1,
+                elf.items,
+                elf.calories
+            ));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Orders elves from least to most deserving of a place in the top N
+#[derive(Debug, PartialEq, Eq)]
+struct Ranked(Elf);
+
+impl Ord for Ranked {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // on a tie, the elf listed first ranks higher
+        (self.0.calories, Reverse(self.0.index)).cmp(&(other.0.calories, Reverse(other.0.index)))
+    }
+}
+
+impl PartialOrd for Ranked {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The best `n` elves seen so far, without holding on to any of the others
+#[derive(Debug)]
+pub struct Leaderboard {
+    n: usize,
+    // a min-heap, so the weakest elf on the board is the one to drop
+    heap: BinaryHeap<Reverse<Ranked>>,
+    seen: usize,
+}
+
+impl Leaderboard {
+    pub fn new(n: usize) -> Self {
+        Leaderboard {
+            n,
+            heap: BinaryHeap::new(),
+            seen: 0,
+        }
+    }
+
+    pub fn push(&mut self, elf: Elf) {
+        self.seen += 1;
+        self.heap.push(Reverse(Ranked(elf)));
+        if self.heap.len() > self.n {
+            self.heap.pop();
+        }
+    }
+
+    /// How many elves have been pushed, including the ones that didn't make it
+    pub fn seen(&self) -> usize {
+        self.seen
+    }
+
+    /// The elves on the board, in input order
+    pub fn into_inventory(self) -> Inventory {
+        let mut elves: Vec<_> = self
+            .heap
+            .into_iter()
+            .map(|Reverse(Ranked(elf))| elf)
+            .collect();
+        elves.sort_by_key(|elf| elf.index);
+        Inventory { elves }
+    }
+}
+
+impl Extend<Elf> for Leaderboard {
+    fn extend<I: IntoIterator<Item = Elf>>(&mut self, elves: I) {
+        for elf in elves {
+            self.push(elf);
+        }
+    }
+}
+
+/// Reads an inventory one line at a time, keeping only the top `n` elves.
+/// Carriage returns and runs of blank lines between elves are fine.
+pub fn read(mut reader: impl BufRead, n: usize) -> Result<Leaderboard, ReadError> {
+    let mut board = Leaderboard::new(n);
+    let mut current: Option<Elf> = None;
+    let mut buf = String::new();
+    let mut number = 0;
+    loop {
+        buf.clear();
+        let read = reader
+            .read_line(&mut buf)
+            .map_err(|e| ReadError::Input(InputError::Io("inventory".into(), e)))?;
+        if read == 0 {
+            break;
+        }
+        number += 1;
+        let text = buf.trim_end_matches(['\r', '\n']);
+        if text.trim().is_empty() {
+            board.extend(current.take());
+            continue;
+        }
+        let line = Line {
+            day: Day01::DAY,
+            number,
+            text,
+        };
+        let calories: u32 = line.parse(text, "a calorie count")?;
+        let elf = current.get_or_insert_with(|| Elf {
+            index: board.seen(),
+            items: 0,
+            calories: 0,
+        });
+        elf.items += 1;
+        elf.calories += calories;
+    }
+    board.extend(current);
+    Ok(board)
+}
+
+pub fn parse(input: &str) -> Result<Inventory, ParseError> {
+    let groups = parse::blocks(Day01::DAY, input)
+        .iter()
+        .map(|elf| {
+            elf.iter()
+                .map(|line| line.parse::<u32>(line.text, "a calorie count"))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Inventory::from_groups(groups))
+}
+
+pub fn part1(inventory: &Inventory) -> u32 {
+    inventory.top_total(1)
+}
+
+pub fn part2(inventory: &Inventory) -> u32 {
+    inventory.top_total(3)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Inventory;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(inventory: &Self::Input) -> Result<u32, SolveError> {
+        Ok(part1(inventory))
+    }
+
+    fn part2(inventory: &Self::Input) -> Result<u32, SolveError> {
+        Ok(part2(inventory))
+    }
+}
@@ -0,0 +1,426 @@
+use std::{error::Error, fmt::Display};
+
+use itertools::Itertools;
+
+use crate::{
+    error::ParseError,
+    parse::{self, Line},
+    solution::{Solution, SolveError},
+};
+
+/// A shape, as its position in a [`Game`]'s cycle
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Shape(pub usize);
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+}
+
+/// A cyclic dominance game with an odd number of shapes, where every shape
+/// beats the half of the others that come just before it in the cycle (and
+/// loses to the half just after). Rock, paper, scissors is the three-shape
+/// case, since paper beats rock, scissors beat paper and rock beats scissors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub shapes: Vec<String>,
+    /// Bonus for playing each shape
+    pub scores: Vec<u32>,
+    /// Points for a loss, a draw and a win
+    pub outcome_scores: [u32; 3],
+    /// How the guide's first column writes each shape
+    pub opponent_symbols: Vec<String>,
+    /// Every symbol allowed in the guide's second column, which different
+    /// readings of the guide map to shapes or outcomes
+    pub columns: Vec<String>,
+}
+
+impl Game {
+    /// A game over the named shapes, in cycle order. Shapes score 1, 2, 3, ...
+    /// and both columns of the guide use their names.
+    ///
+    /// Panics unless there are an odd number of shapes, and at least three.
+    pub fn cyclic(shapes: &[&str]) -> Self {
+        assert!(
+            shapes.len() >= 3 && shapes.len() % 2 == 1,
+            "a cyclic game needs an odd number of shapes, and at least three"
+        );
+        let shapes: Vec<String> = shapes.iter().map(|s| s.to_string()).collect();
+        Game {
+            scores: (1..=shapes.len() as u32).collect(),
+            outcome_scores: [0, 3, 6],
+            opponent_symbols: shapes.clone(),
+            columns: shapes.clone(),
+            shapes,
+        }
+    }
+
+    /// The puzzle's game: A/B/C for the opponent and X/Y/Z for the response
+    pub fn rock_paper_scissors() -> Self {
+        Game::cyclic(&["Rock", "Paper", "Scissors"])
+            .with_symbols(&["A", "B", "C"], &["X", "Y", "Z"])
+    }
+
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Game::cyclic(&["Rock", "Spock", "Paper", "Lizard", "Scissors"])
+    }
+
+    /// A game of `n` shapes named `1` to `n`, like RPS-101
+    pub fn numbered(n: usize) -> Self {
+        let names: Vec<_> = (1..=n).map(|i| i.to_string()).collect();
+        Game::cyclic(&names.iter().map(String::as_str).collect::<Vec<_>>())
+    }
+
+    pub fn with_scores(mut self, scores: &[u32]) -> Self {
+        assert_eq!(self.shapes.len(), scores.len(), "one score per shape");
+        self.scores = scores.to_vec();
+        self
+    }
+
+    pub fn with_outcome_scores(mut self, loss: u32, draw: u32, win: u32) -> Self {
+        self.outcome_scores = [loss, draw, win];
+        self
+    }
+
+    pub fn with_symbols(mut self, opponent: &[&str], columns: &[&str]) -> Self {
+        assert_eq!(self.shapes.len(), opponent.len(), "one symbol per shape");
+        self.opponent_symbols = opponent.iter().map(|s| s.to_string()).collect();
+        self.columns = columns.iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    pub fn beats(&self, a: Shape, b: Shape) -> bool {
+        let n = self.shapes.len();
+        (1..=n / 2).contains(&((a.0 + n - b.0) % n))
+    }
+
+    pub fn outcome(&self, player: Shape, opponent: Shape) -> Outcome {
+        if player == opponent {
+            Outcome::Draw
+        } else if self.beats(player, opponent) {
+            Outcome::Win
+        } else {
+            Outcome::Loss
+        }
+    }
+
+    /// The shape to play against `opponent` for the given outcome. With more
+    /// than three shapes there's a choice, so this takes the nearest one in
+    /// the cycle.
+    pub fn respond(&self, opponent: Shape, outcome: Outcome) -> Shape {
+        let n = self.shapes.len();
+        match outcome {
+            Outcome::Loss => Shape((opponent.0 + n - 1) % n),
+            Outcome::Draw => opponent,
+            Outcome::Win => Shape((opponent.0 + 1) % n),
+        }
+    }
+
+    pub fn score(&self, player: Shape, opponent: Shape) -> u32 {
+        let outcome = self.outcome(player, opponent);
+        self.outcome_scores[outcome as usize] + self.scores[player.0]
+    }
+
+    /// Reads a line of the guide, leaving the second column uninterpreted
+    pub fn parse_entry(&self, line: Line) -> Result<Entry, ParseError> {
+        let opponent_expected =
+            format!("the opponent's shape ({})", or_list(&self.opponent_symbols));
+        let column_expected = format!("a response ({})", or_list(&self.columns));
+        let mut tokens = line.tokens();
+        let token = tokens.next(&opponent_expected)?;
+        let opponent = match symbol(&self.opponent_symbols, token) {
+            Some(i) => Shape(i),
+            None => return Err(line.error(token, opponent_expected)),
+        };
+        let token = tokens.next(&column_expected)?;
+        let column = match symbol(&self.columns, token) {
+            Some(i) => i,
+            None => return Err(line.error(token, column_expected)),
+        };
+        tokens.end()?;
+        Ok(Entry { opponent, column })
+    }
+}
+
+fn symbol(symbols: &[String], token: &str) -> Option<usize> {
+    symbols.iter().position(|s| s.eq_ignore_ascii_case(token))
+}
+
+/// Lists symbols as `A, B or C`
+fn or_list(symbols: &[String]) -> String {
+    match symbols.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} or {last}", rest.join(", ")),
+        None => String::new(),
+    }
+}
+
+/// A line of the strategy guide: the opponent's shape and the index of the
+/// second column's symbol in [`Game::columns`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Entry {
+    pub opponent: Shape,
+    pub column: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Round {
+    pub opponent: Shape,
+    pub player: Shape,
+}
+
+impl Round {
+    pub fn parse_part1(game: &Game, line: Line) -> Result<Self, ParseError> {
+        let entry = game.parse_entry(line)?;
+        if entry.column >= game.shapes.len() {
+            return Err(line.error(line.text.split_whitespace().last().unwrap(), "a shape"));
+        }
+        Ok(Round::part1(game, entry))
+    }
+
+    pub fn parse_part2(game: &Game, line: Line) -> Result<Self, ParseError> {
+        let entry = game.parse_entry(line)?;
+        if entry.column >= Outcome::ALL.len() {
+            return Err(line.error(line.text.split_whitespace().last().unwrap(), "an outcome"));
+        }
+        Ok(Round::part2(game, entry))
+    }
+
+    /// The second column is the shape to play, in cycle order.
+    ///
+    /// Panics if the game has fewer shapes than columns, which
+    /// [`Round::parse_part1`] rejects.
+    pub fn part1(game: &Game, entry: Entry) -> Self {
+        assert!(entry.column < game.shapes.len(), "column isn't a shape");
+        Round {
+            opponent: entry.opponent,
+            player: Shape(entry.column),
+        }
+    }
+
+    /// The second column is how the round needs to end: lose, draw or win.
+    ///
+    /// Panics if the column is past the third, which [`Round::parse_part2`]
+    /// rejects.
+    pub fn part2(game: &Game, entry: Entry) -> Self {
+        Round {
+            opponent: entry.opponent,
+            player: game.respond(entry.opponent, Outcome::ALL[entry.column]),
+        }
+    }
+
+    pub fn score(&self, game: &Game) -> u32 {
+        game.score(self.player, self.opponent)
+    }
+}
+
+/// One way of reading the guide's second column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reading {
+    /// Each column symbol is the shape to play
+    Shapes(Vec<Shape>),
+    /// Each column symbol is how the round should end
+    Outcomes(Vec<Outcome>),
+}
+
+impl Reading {
+    pub fn round(&self, game: &Game, entry: Entry) -> Round {
+        let player = match self {
+            Reading::Shapes(shapes) => shapes[entry.column],
+            Reading::Outcomes(outcomes) => game.respond(entry.opponent, outcomes[entry.column]),
+        };
+        Round {
+            opponent: entry.opponent,
+            player,
+        }
+    }
+
+    /// Spells the reading out, e.g. `X=Rock, Y=Paper, Z=Scissors`
+    pub fn describe(&self, game: &Game) -> String {
+        let meanings: Vec<_> = match self {
+            Reading::Shapes(shapes) => shapes.iter().map(|s| game.shapes[s.0].clone()).collect(),
+            Reading::Outcomes(outcomes) => outcomes.iter().map(|o| format!("{o:?}")).collect(),
+        };
+        game.columns
+            .iter()
+            .zip(meanings)
+            .map(|(symbol, meaning)| format!("{symbol}={meaning}"))
+            .join(", ")
+    }
+}
+
+/// How the whole guide plays out under one reading
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interpretation {
+    pub reading: Reading,
+    pub score: u32,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+}
+
+impl Interpretation {
+    pub fn new(game: &Game, guide: &[Entry], reading: Reading) -> Self {
+        let mut interpretation = Interpretation {
+            reading,
+            score: 0,
+            wins: 0,
+            draws: 0,
+            losses: 0,
+        };
+        for &entry in guide {
+            let round = interpretation.reading.round(game, entry);
+            interpretation.score += round.score(game);
+            match game.outcome(round.player, round.opponent) {
+                Outcome::Win => interpretation.wins += 1,
+                Outcome::Draw => interpretation.draws += 1,
+                Outcome::Loss => interpretation.losses += 1,
+            }
+        }
+        interpretation
+    }
+}
+
+/// The most second-column symbols [`interpretations`] will try every
+/// arrangement of: there are `n!` of them, and 8! is already 40,320 passes
+/// over the guide
+pub const MAX_COLUMNS: usize = 8;
+
+/// Why a guide has too many readings to rank
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooManyColumns {
+    pub columns: usize,
+}
+
+impl Display for TooManyColumns {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "can't rank every reading of {} columns (at most {MAX_COLUMNS})",
+            self.columns
+        )
+    }
+}
+
+impl Error for TooManyColumns {}
+
+/// The `top` best consistent readings of the second column, best score first:
+/// each symbol a different shape (if there are as many symbols as shapes), or
+/// each a different outcome (if there are three). Readings are scored one at a
+/// time, and only the best `top` so far are kept.
+pub fn interpretations(
+    game: &Game,
+    guide: &[Entry],
+    top: usize,
+) -> Result<Vec<Interpretation>, TooManyColumns> {
+    let columns = game.columns.len();
+    if columns > MAX_COLUMNS {
+        return Err(TooManyColumns { columns });
+    }
+    let shapes = (columns == game.shapes.len())
+        .then(|| (0..columns).map(Shape).permutations(columns))
+        .into_iter()
+        .flatten()
+        .map(Reading::Shapes);
+    let outcomes = (columns == Outcome::ALL.len())
+        .then(|| Outcome::ALL.into_iter().permutations(columns))
+        .into_iter()
+        .flatten()
+        .map(Reading::Outcomes);
+    let mut ranked: Vec<Interpretation> = Vec::new();
+    for reading in shapes.chain(outcomes) {
+        let interpretation = Interpretation::new(game, guide, reading);
+        // after any with the same score, so ties keep the order they were found in
+        let rank = ranked.partition_point(|i| i.score >= interpretation.score);
+        if rank < top {
+            ranked.insert(rank, interpretation);
+            ranked.truncate(top);
+        }
+    }
+    Ok(ranked)
+}
+
+/// A table of the `top` best interpretations
+pub fn report(game: &Game, guide: &[Entry], top: usize) -> Result<String, TooManyColumns> {
+    let rows: Vec<_> = interpretations(game, guide, top)?
+        .into_iter()
+        .map(|i| (i.reading.describe(game), i))
+        .collect();
+    let width = rows
+        .iter()
+        .map(|(reading, _)| reading.len())
+        .max()
+        .unwrap_or(0);
+    let mut lines = vec![format!(
+        "{:>4}  {:width$}  {:>6}  {:>5}  {:>5}  {:>6}",
+        "Rank", "Reading", "Score", "Wins", "Draws", "Losses"
+    )];
+    for (rank, (reading, i)) in rows.iter().enumerate() {
+        lines.push(format!(
+            "{:>4}  {reading:width$}  {:>6}  {:>5}  {:>5}  {:>6}",
+            rank + 1,
+            i.score,
+            i.wins,
+            i.draws,
+            i.losses
+        ));
+    }
+    Ok(lines.join("\n"))
+}
+
+/// Reads a guide for any game
+pub fn parse_with(game: &Game, input: &str) -> Result<Vec<Entry>, ParseError> {
+    parse::lines(Day02::DAY, input)
+        .map(|line| game.parse_entry(line))
+        .collect()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Entry>, ParseError> {
+    parse_with(&Game::rock_paper_scissors(), input)
+}
+
+pub fn part1(guide: &[Entry]) -> u32 {
+    let game = Game::rock_paper_scissors();
+    guide
+        .iter()
+        .map(|&entry| Round::part1(&game, entry))
+        .map(|r| r.score(&game))
+        .sum()
+}
+
+pub fn part2(guide: &[Entry]) -> u32 {
+    let game = Game::rock_paper_scissors();
+    guide
+        .iter()
+        .map(|&entry| Round::part2(&game, entry))
+        .map(|r| r.score(&game))
+        .sum()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Entry>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(guide: &Self::Input) -> Result<u32, SolveError> {
+        Ok(part1(guide))
+    }
+
+    fn part2(guide: &Self::Input) -> Result<u32, SolveError> {
+        Ok(part2(guide))
+    }
+}
@@ -0,0 +1,487 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::Display,
+    ops::{BitAnd, BitOr, Sub},
+};
+
+use serde::{Serialize, Serializer};
+
+use crate::{
+    error::ParseError,
+    parse::{self, Line},
+    solution::{Solution, SolveError},
+};
+
+const ITEM: &str = "an item (a-z or A-Z)";
+
+/// How many elves travel together in the puzzle
+pub const GROUP_SIZE: usize = 3;
+
+/// The priority of an item type: a-z are 1 to 26 and A-Z are 27 to 52
+pub fn priority(item: char) -> Option<u32> {
+    match item {
+        'a'..='z' => Some(1 + item as u32 - 'a' as u32),
+        'A'..='Z' => Some(27 + item as u32 - 'A' as u32),
+        _ => None,
+    }
+}
+
+/// The item type with the given priority
+pub fn item(priority: u32) -> Option<char> {
+    match priority {
+        1..=26 => char::from_u32('a' as u32 + priority - 1),
+        27..=52 => char::from_u32('A' as u32 + priority - 27),
+        _ => None,
+    }
+}
+
+/// A set of item types, one bit per priority
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+
+    pub fn insert(&mut self, item: char) -> bool {
+        match priority(item) {
+            Some(p) => {
+                self.0 |= 1 << (p - 1);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn contains(&self, item: char) -> bool {
+        priority(item).is_some_and(|p| self.0 & (1 << (p - 1)) != 0)
+    }
+
+    pub fn len(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The items in the set, lowest priority first
+    pub fn iter(&self) -> impl Iterator<Item = char> {
+        let bits = self.0;
+        (1..=52)
+            .filter(move |p| bits & (1 << (p - 1)) != 0)
+            .filter_map(item)
+    }
+
+    /// The one item in the set, if it holds exactly one
+    pub fn single(&self) -> Option<char> {
+        match self.len() {
+            1 => item(self.0.trailing_zeros() + 1),
+            _ => None,
+        }
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, rhs: Self) -> Self {
+        ItemSet(self.0 & rhs.0)
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = ItemSet;
+
+    fn bitor(self, rhs: Self) -> Self {
+        ItemSet(self.0 | rhs.0)
+    }
+}
+
+impl Sub for ItemSet {
+    type Output = ItemSet;
+
+    fn sub(self, rhs: Self) -> Self {
+        ItemSet(self.0 & !rhs.0)
+    }
+}
+
+/// Characters that aren't item types are skipped, so `"a1b"` collects to `ab`.
+/// Use [`ItemSet::insert`] to find out whether a character was kept.
+impl FromIterator<char> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut set = ItemSet::EMPTY;
+        for c in iter {
+            set.insert(c);
+        }
+        set
+    }
+}
+
+/// Serialized as its items, e.g. `"cZ"`
+impl Serialize for ItemSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl Display for ItemSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.iter().collect::<String>())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rucksack {
+    /// 1-based line number in the input
+    pub line: usize,
+    pub compartments: [ItemSet; 2],
+}
+
+impl Rucksack {
+    pub fn parse(line: Line) -> Result<Self, ParseError> {
+        let mut items = Vec::new();
+        for (i, c) in line.text.char_indices() {
+            match priority(c) {
+                Some(_) => items.push(c),
+                None => return Err(line.error(line.char_at(i), ITEM)),
+            }
+        }
+        if items.is_empty() {
+            return Err(line.error(line.end(), ITEM));
+        }
+        if items.len() % 2 == 1 {
+            let expected = "an even number of items, half in each compartment";
+            return Err(line.error(line.end(), expected));
+        }
+        let (first, second) = items.split_at(items.len() / 2);
+        Ok(Rucksack {
+            line: line.number,
+            compartments: [
+                first.iter().copied().collect(),
+                second.iter().copied().collect(),
+            ],
+        })
+    }
+
+    pub fn items(&self) -> ItemSet {
+        self.compartments[0] | self.compartments[1]
+    }
+
+    /// Items packed into both compartments
+    pub fn misplaced(&self) -> ItemSet {
+        self.compartments[0] & self.compartments[1]
+    }
+
+    /// Priority of the one item in both compartments
+    pub fn priority(&self) -> Result<u32, CommonItemError> {
+        single_priority(self.misplaced(), Source::Rucksack { line: self.line })
+    }
+}
+
+/// Priority of the badge shared by every rucksack in a group. `index` is the
+/// group's 0-based position, which only goes into errors.
+pub fn group_priority(index: usize, group: &[Rucksack]) -> Result<u32, CommonItemError> {
+    let common = group
+        .iter()
+        .map(Rucksack::items)
+        .reduce(|a, b| a & b)
+        .unwrap_or_default();
+    let lines = group.iter().map(|r| r.line).collect();
+    single_priority(common, Source::Group { index, lines })
+}
+
+fn single_priority(common: ItemSet, source: Source) -> Result<u32, CommonItemError> {
+    match common.single() {
+        Some(item) => Ok(priority(item).unwrap()),
+        None => Err(CommonItemError { source, common }),
+    }
+}
+
+/// Where a common item was looked for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Rucksack { line: usize },
+    Group { index: usize, lines: Vec<usize> },
+}
+
+/// A rucksack's compartments, or a group's rucksacks, didn't share exactly one
+/// item type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommonItemError {
+    pub source: Source,
+    /// Every item in common, which is either none or several
+    pub common: ItemSet,
+}
+
+impl Display for CommonItemError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.source {
+            Source::Rucksack { line } => write!(f, "the rucksack on line {line}")?,
+            Source::Group { index, lines } => {
+                let lines: Vec<_> = lines.iter().map(usize::to_string).collect();
+                write!(f, "group {} (lines {})", index + 1, lines.join(", "))?
+            }
+        }
+        match self.common.len() {
+            0 => write!(f, " has no item in common"),
+            n => write!(f, " has {n} items in common ({})", self.common),
+        }
+    }
+}
+
+impl Error for CommonItemError {}
+
+/// Why a group's badge couldn't be found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupError {
+    /// Groups of no rucksacks at all
+    ZeroSize,
+    /// The rucksacks don't divide evenly, so the last group is short
+    Incomplete {
+        /// 0-based position of the group
+        index: usize,
+        lines: Vec<usize>,
+        size: usize,
+    },
+    /// A whole group didn't share exactly one item type
+    Common(CommonItemError),
+}
+
+impl Display for GroupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GroupError::ZeroSize => write!(f, "a group needs at least one rucksack"),
+            GroupError::Incomplete { index, lines, size } => {
+                let count = lines.len();
+                let lines: Vec<_> = lines.iter().map(usize::to_string).collect();
+                write!(
+                    f,
+                    "group {} (lines {}) has {count} rucksack(s) rather than {size}",
+                    index + 1,
+                    lines.join(", ")
+                )
+            }
+            GroupError::Common(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for GroupError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GroupError::ZeroSize | GroupError::Incomplete { .. } => None,
+            GroupError::Common(e) => Some(e),
+        }
+    }
+}
+
+impl From<CommonItemError> for GroupError {
+    fn from(e: CommonItemError) -> Self {
+        GroupError::Common(e)
+    }
+}
+
+/// Splits rucksacks into groups of `size`, in input order, as long as `size`
+/// isn't 0 and they divide evenly
+pub fn groups(
+    rucksacks: &[Rucksack],
+    size: usize,
+) -> Result<std::slice::Chunks<'_, Rucksack>, GroupError> {
+    if size == 0 {
+        return Err(GroupError::ZeroSize);
+    }
+    let short = rucksacks.len() % size;
+    if short != 0 {
+        let last = &rucksacks[rucksacks.len() - short..];
+        return Err(GroupError::Incomplete {
+            index: rucksacks.len() / size,
+            lines: last.iter().map(|r| r.line).collect(),
+            size,
+        });
+    }
+    Ok(rucksacks.chunks(size))
+}
+
+pub fn parse(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+    parse::lines(Day03::DAY, input)
+        .map(Rucksack::parse)
+        .collect()
+}
+
+pub fn part1(rucksacks: &[Rucksack]) -> Result<u32, CommonItemError> {
+    rucksacks.iter().map(Rucksack::priority).sum()
+}
+
+pub fn part2(rucksacks: &[Rucksack]) -> Result<u32, GroupError> {
+    let mut total = 0;
+    for (i, group) in groups(rucksacks, GROUP_SIZE)?.enumerate() {
+        total += group_priority(i, group)?;
+    }
+    Ok(total)
+}
+
+/// The items every rucksack in a group has in common
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Badges {
+    /// 0-based position of the group
+    pub index: usize,
+    pub lines: Vec<usize>,
+    pub candidates: ItemSet,
+}
+
+/// The badge candidates of each group of `size`, which fails like [`groups`]
+pub fn badges(rucksacks: &[Rucksack], size: usize) -> Result<Vec<Badges>, GroupError> {
+    let badges = groups(rucksacks, size)?
+        .enumerate()
+        .map(|(index, group)| Badges {
+            index,
+            lines: group.iter().map(|r| r.line).collect(),
+            candidates: group
+                .iter()
+                .map(Rucksack::items)
+                .reduce(|a, b| a & b)
+                .unwrap_or_default(),
+        })
+        .collect();
+    Ok(badges)
+}
+
+/// How many compartments, rucksacks and groups hold an item type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ItemCount {
+    pub item: char,
+    pub priority: u32,
+    pub compartments: usize,
+    pub rucksacks: usize,
+    pub groups: usize,
+}
+
+/// Items found in both compartments of a rucksack
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Misplaced {
+    pub line: usize,
+    /// Each item with its priority
+    pub items: Vec<(char, u32)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Analysis {
+    pub group_size: usize,
+    pub groups: Vec<Badges>,
+    /// Every item type that turns up at all, by priority
+    pub catalog: Vec<ItemCount>,
+    /// Rucksacks with at least one misplaced item
+    pub misplaced: Vec<Misplaced>,
+}
+
+impl Analysis {
+    /// Fails like [`groups`] if the rucksacks can't be split into groups of
+    /// `group_size`
+    pub fn new(rucksacks: &[Rucksack], group_size: usize) -> Result<Self, GroupError> {
+        let groups = badges(rucksacks, group_size)?;
+        let mut counts: BTreeMap<u32, ItemCount> = BTreeMap::new();
+        let mut count = |set: ItemSet, field: fn(&mut ItemCount) -> &mut usize| {
+            for item in set.iter() {
+                let priority = priority(item).unwrap();
+                let entry = counts.entry(priority).or_insert(ItemCount {
+                    item,
+                    priority,
+                    compartments: 0,
+                    rucksacks: 0,
+                    groups: 0,
+                });
+                *field(entry) += 1;
+            }
+        };
+        for rucksack in rucksacks {
+            for compartment in rucksack.compartments {
+                count(compartment, |c| &mut c.compartments);
+            }
+            count(rucksack.items(), |c| &mut c.rucksacks);
+        }
+        for group in rucksacks.chunks(group_size) {
+            let items = group.iter().fold(ItemSet::EMPTY, |a, r| a | r.items());
+            count(items, |c| &mut c.groups);
+        }
+        let misplaced = rucksacks
+            .iter()
+            .filter(|r| !r.misplaced().is_empty())
+            .map(|r| Misplaced {
+                line: r.line,
+                items: r
+                    .misplaced()
+                    .iter()
+                    .map(|item| (item, priority(item).unwrap()))
+                    .collect(),
+            })
+            .collect();
+        Ok(Analysis {
+            group_size,
+            groups,
+            catalog: counts.into_values().collect(),
+            misplaced,
+        })
+    }
+
+    /// Badge candidates, the item catalog and misplaced items as plain text
+    /// tables, one after the other
+    pub fn table(&self) -> String {
+        let mut lines = vec![format!("{:>5}  {:<12}  Candidates", "Group", "Lines")];
+        for group in &self.groups {
+            let range = match (group.lines.first(), group.lines.last()) {
+                (Some(first), Some(last)) => format!("{first}-{last}"),
+                _ => String::new(),
+            };
+            lines.push(format!(
+                "{:>5}  {range:<12}  {}",
+                group.index + 1,
+                group.candidates
+            ));
+        }
+        lines.push(String::new());
+        lines.push(format!(
+            "{:>4}  {:>8}  {:>12}  {:>9}  {:>6}",
+            "Item", "Priority", "Compartments", "Rucksacks", "Groups"
+        ));
+        for c in &self.catalog {
+            lines.push(format!(
+                "{:>4}  {:>8}  {:>12}  {:>9}  {:>6}",
+                c.item, c.priority, c.compartments, c.rucksacks, c.groups
+            ));
+        }
+        lines.push(String::new());
+        lines.push(format!("{:>4}  Misplaced", "Line"));
+        for m in &self.misplaced {
+            let items: Vec<_> = m.items.iter().map(|(i, p)| format!("{i} ({p})")).collect();
+            lines.push(format!("{:>4}  {}", m.line, items.join(", ")));
+        }
+        lines.join("\n")
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Rucksack>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Result<u32, SolveError> {
+        Ok(part1(rucksacks)?)
+    }
+
+    fn part2(rucksacks: &Self::Input) -> Result<u32, SolveError> {
+        Ok(part2(rucksacks)?)
+    }
+}
@@ -0,0 +1,204 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    error::ParseError,
+    interval::{Interval, IntervalSet},
+    parse::{self, Line},
+    solution::{Solution, SolveError},
+};
+
+/// The sections an elf has to clean
+pub type Assignment = Interval<u32>;
+
+pub fn parse(input: &str) -> Result<Vec<(Assignment, Assignment)>, ParseError> {
+    parse::lines(Day04::DAY, input).map(parse_line).collect()
+}
+
+pub fn parse_line(line: Line) -> Result<(Assignment, Assignment), ParseError> {
+    let (l, r) = line.split_once(",", "a comma between the two assignments")?;
+    Ok((parse_assignment(line, l)?, parse_assignment(line, r)?))
+}
+
+pub fn parse_assignment<'a>(line: Line<'a>, pair: &'a str) -> Result<Assignment, ParseError> {
+    let expected = "a section range like 2-4";
+    let (l, r) = pair
+        .split_once('-')
+        .ok_or_else(|| line.error(pair, expected))?;
+    let (l, r): (u32, u32) = (line.parse(l, expected)?, line.parse(r, expected)?);
+    Interval::new(l, r)
+        .ok_or_else(|| line.error(pair, "a range whose start is no greater than its end"))
+}
+
+pub fn part1(pairs: &[(Assignment, Assignment)]) -> usize {
+    pairs
+        .iter()
+        .filter(|(a, b)| a.covers(b) || b.covers(a))
+        .count()
+}
+
+pub fn part2(pairs: &[(Assignment, Assignment)]) -> usize {
+    pairs.iter().filter(|(a, b)| a.overlaps(b)).count()
+}
+
+/// Every elf's assignment across all the pairs, for looking at how the
+/// sections are covered as a whole
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Roster {
+    /// In input order, so elf `i` is on line `i / 2 + 1`
+    pub elves: Vec<Assignment>,
+}
+
+impl Roster {
+    pub fn new(pairs: &[(Assignment, Assignment)]) -> Self {
+        let elves = pairs.iter().flat_map(|&(a, b)| [a, b]).collect();
+        Roster { elves }
+    }
+
+    /// The input line an elf's assignment is on
+    pub fn line(index: usize) -> usize {
+        index / 2 + 1
+    }
+
+    /// From the lowest section anyone covers to the highest
+    pub fn span(&self) -> Option<Interval<u32>> {
+        self.elves.iter().copied().reduce(|a, b| a.hull(&b))
+    }
+
+    /// How many elves cover each section across the span, as runs of sections
+    /// sharing a count. Runs with no elves at all are included.
+    pub fn coverage(&self) -> Vec<(Interval<u32>, usize)> {
+        // how the count changes at each section, sweeping upwards
+        let mut changes: BTreeMap<u64, isize> = BTreeMap::new();
+        for elf in &self.elves {
+            *changes.entry(elf.start.into()).or_default() += 1;
+            *changes.entry(u64::from(elf.end) + 1).or_default() -= 1;
+        }
+        let mut runs = Vec::new();
+        let mut count = 0;
+        let mut changes = changes.into_iter().peekable();
+        while let Some((section, change)) = changes.next() {
+            count += change;
+            if let Some(&(next, _)) = changes.peek() {
+                // both ends fit in a u32, since only the last change is past
+                // an assignment's end
+                let run = Interval::new(section as u32, (next - 1) as u32).unwrap();
+                runs.push((run, count as usize));
+            }
+        }
+        runs
+    }
+
+    /// How many elves cover `section`
+    pub fn count(&self, section: u32) -> usize {
+        self.elves
+            .iter()
+            .filter(|elf| elf.contains(section))
+            .count()
+    }
+
+    /// Sections within the span that no elf covers
+    pub fn uncovered(&self) -> IntervalSet<u32> {
+        match self.span() {
+            Some(span) => self.covered().gaps(span),
+            None => IntervalSet::new(),
+        }
+    }
+
+    pub fn covered(&self) -> IntervalSet<u32> {
+        self.elves.iter().copied().collect()
+    }
+
+    /// Elves whose every section is also covered by someone else, so any one
+    /// of them could be dropped on their own
+    pub fn redundant(&self) -> Vec<usize> {
+        let lone: IntervalSet<u32> = self
+            .coverage()
+            .into_iter()
+            .filter(|&(_, count)| count == 1)
+            .map(|(run, _)| run)
+            .collect();
+        (0..self.elves.len())
+            .filter(|&i| {
+                lone.intersection(&IntervalSet::from(self.elves[i]))
+                    .is_empty()
+            })
+            .collect()
+    }
+
+    /// As few elves as possible that still cover every covered section, by
+    /// index. On a tie the elf listed first is picked.
+    pub fn minimal_cover(&self) -> Vec<usize> {
+        let mut order: Vec<_> = (0..self.elves.len()).collect();
+        order.sort_by_key(|&i| (self.elves[i].start, i));
+        let mut chosen = Vec::new();
+        let mut next = order.iter().peekable();
+        for part in self.covered().iter() {
+            // the lowest section of this part that isn't covered yet
+            let mut from = u64::from(part.start);
+            while from <= part.end.into() {
+                // of the elves starting by `from`, the one reaching furthest
+                let mut best: Option<usize> = None;
+                while let Some(&&i) = next.peek() {
+                    if u64::from(self.elves[i].start) > from {
+                        break;
+                    }
+                    if best.is_none_or(|b| self.elves[i].end > self.elves[b].end) {
+                        best = Some(i);
+                    }
+                    next.next();
+                }
+                // `from` is covered, so some elf starting by it reaches it
+                let best = best.unwrap();
+                chosen.push(best);
+                from = u64::from(self.elves[best].end) + 1;
+            }
+        }
+        chosen.sort_unstable();
+        chosen
+    }
+
+    /// Coverage per run of sections, then the uncovered sections, redundant
+    /// elves and a minimal cover. Elves are numbered from 1 in input order.
+    pub fn report(&self) -> String {
+        let elf = |i: usize| format!("{} (line {}, {})", i + 1, Roster::line(i), self.elves[i]);
+        let mut lines = vec![format!("{:>11}  {:>4}", "Sections", "Elves")];
+        for (run, count) in self.coverage() {
+            lines.push(format!("{:>11}  {count:>4}", run.to_string()));
+        }
+        let uncovered: Vec<_> = self.uncovered().iter().map(|i| i.to_string()).collect();
+        lines.push(String::new());
+        lines.push(match uncovered.len() {
+            0 => "Uncovered: none".into(),
+            _ => format!("Uncovered: {}", uncovered.join(", ")),
+        });
+        let redundant = self.redundant();
+        lines.push(format!("Redundant elves: {}", redundant.len()));
+        lines.extend(redundant.into_iter().map(|i| format!("  elf {}", elf(i))));
+        let cover = self.minimal_cover();
+        lines.push(format!("Minimal cover: {} elves", cover.len()));
+        lines.extend(cover.into_iter().map(|i| format!("  elf {}", elf(i))));
+        lines.join("\n")
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<(Assignment, Assignment)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(pairs: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part1(pairs))
+    }
+
+    fn part2(pairs: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part2(pairs))
+    }
+}
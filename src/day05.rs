@@ -0,0 +1,594 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    error::Error,
+    fmt::Display,
+};
+
+use crate::{
+    error::ParseError,
+    parse::{self, Line},
+    solution::{Solution, SolveError},
+};
+
+const STACK: &str = "a stack number (starting from 1)";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Instruction {
+    pub quantity: usize,
+    pub source: usize,
+    pub destination: usize,
+}
+
+impl Instruction {
+    /// Parses a line like `move 1 from 2 to 3`
+    pub fn parse(line: Line) -> Result<Self, ParseError> {
+        let mut tokens = line.tokens();
+        tokens.keyword("move")?;
+        let quantity = tokens.parse("a number of crates")?;
+        tokens.keyword("from")?;
+        let source = Self::parse_stack(line, tokens.next(STACK)?)?;
+        tokens.keyword("to")?;
+        let destination = Self::parse_stack(line, tokens.next(STACK)?)?;
+        tokens.end()?;
+        Ok(Instruction {
+            quantity,
+            source,
+            destination,
+        })
+    }
+
+    fn parse_stack<'a>(line: Line<'a>, token: &'a str) -> Result<usize, ParseError> {
+        match line.parse::<usize>(token, STACK)? {
+            0 => Err(line.error(token, STACK)),
+            n => Ok(n - 1),
+        }
+    }
+
+    /// Makes sure the instruction can be carried out on `stacks`. `index` is
+    /// the instruction's 0-based position in the procedure, for the error.
+    pub fn check(&self, index: usize, stacks: &Stacks) -> Result<(), MoveError> {
+        let error = |kind| Err(MoveError { index, kind });
+        for stack in [self.source, self.destination] {
+            if stack >= stacks.0.len() {
+                let count = stacks.0.len();
+                return error(MoveErrorKind::MissingStack { stack, count });
+            }
+        }
+        let available = stacks.0[self.source].len();
+        if available < self.quantity {
+            return error(MoveErrorKind::Underflow {
+                stack: self.source,
+                wanted: self.quantity,
+                available,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Written the way it's parsed, e.g. `move 1 from 2 to 3`
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.quantity,
+            self.source + 1,
+            self.destination + 1
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveErrorKind {
+    /// `stack` is 0-based, and there are only `count` stacks
+    MissingStack { stack: usize, count: usize },
+    /// The instruction wants more crates than `stack` holds
+    Underflow {
+        stack: usize,
+        wanted: usize,
+        available: usize,
+    },
+}
+
+/// An instruction that can't be carried out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MoveError {
+    /// 0-based position in the procedure
+    pub index: usize,
+    pub kind: MoveErrorKind,
+}
+
+impl Display for MoveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "instruction {}: ", self.index + 1)?;
+        match self.kind {
+            MoveErrorKind::MissingStack { stack, count } => {
+                write!(f, "there is no stack {} (only {count})", stack + 1)
+            }
+            MoveErrorKind::Underflow {
+                stack,
+                wanted,
+                available,
+            } => write!(
+                f,
+                "can't move {wanted} crate(s) from stack {}, which holds {available}",
+                stack + 1
+            ),
+        }
+    }
+}
+
+impl Error for MoveError {}
+
+/// A model of crane, which decides how many crates to pick up at a time.
+/// Crates lifted together keep their order.
+pub trait Crane {
+    fn name(&self) -> String;
+
+    /// How many crates to pick up in one lift when `remaining` still have to
+    /// be moved. Anything outside `1..=remaining` is clamped into it.
+    fn lift(&self, remaining: usize) -> usize;
+
+    /// Carries out one instruction, leaving the stacks untouched if it can't
+    fn apply(
+        &self,
+        index: usize,
+        instruction: &Instruction,
+        stacks: &mut Stacks,
+    ) -> Result<(), MoveError> {
+        instruction.check(index, stacks)?;
+        let mut remaining = instruction.quantity;
+        while remaining > 0 {
+            let n = self.lift(remaining).clamp(1, remaining);
+            let source = &mut stacks.0[instruction.source];
+            let lifted: Vec<_> = source.drain(source.len() - n..).collect();
+            stacks.0[instruction.destination].extend(lifted);
+            remaining -= n;
+        }
+        Ok(())
+    }
+
+    /// Carries out a whole procedure, stopping at the first instruction that
+    /// can't be done
+    fn run(&self, stacks: &Stacks, instructions: &[Instruction]) -> Result<Stacks, MoveError> {
+        let mut stacks = stacks.clone();
+        for (index, instruction) in instructions.iter().enumerate() {
+            self.apply(index, instruction, &mut stacks)?;
+        }
+        Ok(stacks)
+    }
+}
+
+/// Moves crates one at a time, so they land in reverse order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn name(&self) -> String {
+        "CrateMover 9000".into()
+    }
+
+    fn lift(&self, _: usize) -> usize {
+        1
+    }
+}
+
+/// Moves all the crates at once, so they keep their order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn name(&self) -> String {
+        "CrateMover 9001".into()
+    }
+
+    fn lift(&self, remaining: usize) -> usize {
+        remaining
+    }
+}
+
+/// Moves up to `capacity` crates per lift. A capacity of 1 behaves like the
+/// CrateMover 9000, and 0 is treated as 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limited {
+    pub capacity: usize,
+}
+
+impl Crane for Limited {
+    fn name(&self) -> String {
+        format!("{}-crate crane", self.capacity)
+    }
+
+    fn lift(&self, remaining: usize) -> usize {
+        remaining.min(self.capacity)
+    }
+}
+
+/// Crates in each stack, bottom first
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Stacks(pub Vec<Vec<char>>);
+
+impl Stacks {
+    /// Parses the drawing of the stacks: rows of crates like `[A]`, four
+    /// columns per stack, above a footer numbering the stacks from 1. Lines
+    /// may be cut short or padded with trailing whitespace.
+    pub fn parse(lines: &[Line]) -> Result<Self, ParseError> {
+        let Some((footer, rows)) = lines.split_last() else {
+            return Err(parse::end_of_input(Day05::DAY, "", "a stack-number footer"));
+        };
+        let mut count = 0;
+        for token in footer.text.split_whitespace() {
+            let expected = format!("stack number {}", count + 1);
+            if footer.parse::<usize>(token, &expected).ok() != Some(count + 1) {
+                return Err(footer.error(token, expected));
+            }
+            count += 1;
+        }
+        let mut stacks = vec![vec![]; count];
+        // whether each stack has had a gap, which nothing can rest on
+        let mut gap = vec![false; count];
+        for row in rows.iter().rev() {
+            let crates = Self::parse_row(*row, count)?;
+            for (i, slot) in crates.into_iter().enumerate() {
+                match slot {
+                    Some((_, c)) if !gap[i] => stacks[i].push(c),
+                    Some((token, _)) => {
+                        let expected = "a crate resting on another crate or the floor";
+                        return Err(row.error(token, expected));
+                    }
+                    None => gap[i] = true,
+                }
+            }
+        }
+        Ok(Stacks(stacks))
+    }
+
+    /// The crate in each of `count` stacks on one row of the drawing, with
+    /// the token it was read from
+    fn parse_row<'a>(
+        row: Line<'a>,
+        count: usize,
+    ) -> Result<Vec<Option<(&'a str, char)>>, ParseError> {
+        let chars: Vec<_> = row.text.char_indices().collect();
+        let mut slots = vec![None; count];
+        for (i, column) in chars.chunks(4).enumerate() {
+            let (slot, separator) = column.split_at(column.len().min(3));
+            if let Some(&(at, c)) = separator.first() {
+                if !c.is_whitespace() {
+                    return Err(row.error(row.char_at(at), "a space between stacks"));
+                }
+            }
+            let Some(&(start, _)) = slot.iter().find(|(_, c)| !c.is_whitespace()) else {
+                continue;
+            };
+            let token = &row.text[slot[0].0..column.get(3).map_or(row.text.len(), |c| c.0)];
+            match slot {
+                [(_, '['), (_, c), (_, ']')] if !c.is_whitespace() && i < count => {
+                    slots[i] = Some((token, *c));
+                }
+                _ if i >= count => {
+                    let expected = format!("end of line after stack {count}");
+                    return Err(row.error(row.char_at(start), expected));
+                }
+                _ => return Err(row.error(token, "a crate like [A] or blank space")),
+            }
+        }
+        Ok(slots)
+    }
+
+    /// The crate on top of each stack, leaving out empty stacks
+    pub fn tops(&self) -> String {
+        self.0.iter().filter_map(|stack| stack.last()).collect()
+    }
+
+    /// Every stack on one line, bottom first, with `-` for an empty stack
+    pub fn summary(&self) -> String {
+        let stacks: Vec<String> = self
+            .0
+            .iter()
+            .map(|stack| match stack.is_empty() {
+                true => "-".into(),
+                false => stack.iter().collect(),
+            })
+            .collect();
+        stacks.join(" ")
+    }
+}
+
+/// Draws the stacks the way the puzzle does, padding every line to the same
+/// width
+impl Display for Stacks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let height = self.0.iter().map(Vec::len).max().unwrap_or(0);
+        for level in (0..height).rev() {
+            let row: Vec<_> = self
+                .0
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(c) => format!("[{c}]"),
+                    None => "   ".into(),
+                })
+                .collect();
+            writeln!(f, "{}", row.join(" "))?;
+        }
+        let footer: Vec<_> = (1..=self.0.len()).map(|n| format!("{n:^3}")).collect();
+        write!(f, "{}", footer.join(" "))
+    }
+}
+
+/// Plays a procedure one instruction at a time, remembering every state on
+/// the way so it can go back as well as forward
+pub struct Replay<'a> {
+    crane: &'a dyn Crane,
+    instructions: &'a [Instruction],
+    /// `history[k]` is the stacks after the first `k` instructions, as far as
+    /// they've been played
+    history: Vec<Stacks>,
+    position: usize,
+}
+
+impl<'a> Replay<'a> {
+    pub fn new(crane: &'a dyn Crane, stacks: &Stacks, instructions: &'a [Instruction]) -> Self {
+        Replay {
+            crane,
+            instructions,
+            history: vec![stacks.clone()],
+            position: 0,
+        }
+    }
+
+    /// How many instructions have been carried out to reach the current state
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn current(&self) -> &Stacks {
+        &self.history[self.position]
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.position == self.instructions.len()
+    }
+
+    /// Carries out the next instruction, returning `false` at the end of the
+    /// procedure
+    pub fn step_forward(&mut self) -> Result<bool, MoveError> {
+        if self.is_finished() {
+            return Ok(false);
+        }
+        self.after(self.position + 1)?;
+        self.position += 1;
+        Ok(true)
+    }
+
+    /// Undoes the last instruction, returning `false` at the start
+    pub fn step_back(&mut self) -> bool {
+        match self.position.checked_sub(1) {
+            Some(position) => {
+                self.position = position;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The stacks after the first `k` instructions, or after all of them if
+    /// there are fewer. Doesn't move the current position.
+    pub fn after(&mut self, k: usize) -> Result<&Stacks, MoveError> {
+        let k = k.min(self.instructions.len());
+        while self.history.len() <= k {
+            let index = self.history.len() - 1;
+            let mut stacks = self.history[index].clone();
+            self.crane
+                .apply(index, &self.instructions[index], &mut stacks)?;
+            self.history.push(stacks);
+        }
+        Ok(&self.history[k])
+    }
+
+    /// The 0-based index of the first instruction that brings `label` to the
+    /// top of a stack it wasn't already on top of. That includes a crate moved
+    /// from one stack's top to another's, but not one that sits on top of the
+    /// same stack throughout.
+    pub fn first_on_top(&mut self, label: char) -> Result<Option<usize>, MoveError> {
+        let on_top = |stacks: &Stacks| -> Vec<bool> {
+            stacks
+                .0
+                .iter()
+                .map(|stack| stack.last() == Some(&label))
+                .collect()
+        };
+        let mut before = on_top(self.after(0)?);
+        for k in 1..=self.instructions.len() {
+            let after = on_top(self.after(k)?);
+            if after.iter().zip(&before).any(|(&now, &was)| now && !was) {
+                return Ok(Some(k - 1));
+            }
+            before = after;
+        }
+        Ok(None)
+    }
+
+    /// One line per state, starting with the initial stacks, each naming the
+    /// instruction that led to it. The lines only depend on the stacks, so
+    /// traces from two cranes can be diffed line by line.
+    pub fn trace(&mut self) -> Result<Vec<String>, MoveError> {
+        self.after(self.instructions.len())?;
+        let mut lines = vec![format!(
+            "{:>4}  {:<22}  {}",
+            0,
+            "start",
+            self.history[0].summary()
+        )];
+        for (k, instruction) in self.instructions.iter().enumerate() {
+            lines.push(format!(
+                "{:>4}  {:<22}  {}",
+                k + 1,
+                instruction.to_string(),
+                self.history[k + 1].summary()
+            ));
+        }
+        Ok(lines)
+    }
+}
+
+/// The first line where two traces differ, as a 0-based index
+pub fn divergence(a: &[String], b: &[String]) -> Option<usize> {
+    (0..a.len().max(b.len())).find(|&i| a.get(i) != b.get(i))
+}
+
+/// What a planned procedure should end up with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    /// Exactly these stacks
+    Stacks(Stacks),
+    /// These crates on top, as [`Stacks::tops`] reads them
+    Tops(String),
+}
+
+impl Target {
+    pub fn reached(&self, stacks: &Stacks) -> bool {
+        match self {
+            Target::Stacks(target) => stacks == target,
+            Target::Tops(tops) => stacks.tops() == *tops,
+        }
+    }
+
+    /// Whether the target could be reached from `stacks` at all, judging by
+    /// the crates there are to work with
+    fn possible(&self, stacks: &Stacks) -> bool {
+        let sorted = |stacks: &Stacks| {
+            let mut crates: Vec<_> = stacks.0.iter().flatten().copied().collect();
+            crates.sort_unstable();
+            crates
+        };
+        match self {
+            Target::Stacks(target) => {
+                target.0.len() == stacks.0.len() && sorted(target) == sorted(stacks)
+            }
+            Target::Tops(tops) => tops.chars().count() <= stacks.0.len(),
+        }
+    }
+}
+
+/// Every instruction that can be carried out on `stacks`
+fn moves(stacks: &Stacks) -> impl Iterator<Item = Instruction> + '_ {
+    let count = stacks.0.len();
+    (0..count).flat_map(move |source| {
+        (0..count)
+            .filter(move |&destination| destination != source)
+            .flat_map(move |destination| {
+                (1..=stacks.0[source].len()).map(move |quantity| Instruction {
+                    quantity,
+                    source,
+                    destination,
+                })
+            })
+    })
+}
+
+/// The shortest procedure, of at most `max_moves` instructions, that takes
+/// `crane` from `start` to `target`. The search is breadth-first, so it's
+/// only practical for a handful of crates.
+pub fn plan(
+    crane: &dyn Crane,
+    start: &Stacks,
+    target: &Target,
+    max_moves: usize,
+) -> Option<Vec<Instruction>> {
+    if !target.possible(start) {
+        return None;
+    }
+    // every state found so far, with the state and instruction it came from
+    let mut states: Vec<(Stacks, Option<(usize, Instruction)>)> = vec![(start.clone(), None)];
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(0, 0)]);
+    let mut found = target.reached(start).then_some(0);
+    while found.is_none() {
+        let Some((index, depth)) = queue.pop_front() else {
+            break;
+        };
+        if depth == max_moves {
+            continue;
+        }
+        let stacks = states[index].0.clone();
+        for instruction in moves(&stacks) {
+            let mut next = stacks.clone();
+            // moves only lists instructions that can be carried out
+            crane.apply(0, &instruction, &mut next).unwrap();
+            if !seen.insert(next.clone()) {
+                continue;
+            }
+            let reached = target.reached(&next);
+            states.push((next, Some((index, instruction))));
+            if reached {
+                found = Some(states.len() - 1);
+                break;
+            }
+            queue.push_back((states.len() - 1, depth + 1));
+        }
+    }
+    let mut procedure = Vec::new();
+    let mut at = found?;
+    while let Some((parent, instruction)) = states[at].1 {
+        procedure.push(instruction);
+        at = parent;
+    }
+    procedure.reverse();
+    Some(procedure)
+}
+
+/// Writes a procedure out one instruction per line, ready to be parsed again
+pub fn procedure(instructions: &[Instruction]) -> String {
+    let lines: Vec<_> = instructions.iter().map(Instruction::to_string).collect();
+    lines.join("\n")
+}
+
+pub fn parse_procedure(lines: &[Line]) -> Result<Vec<Instruction>, ParseError> {
+    lines.iter().copied().map(Instruction::parse).collect()
+}
+
+/// Splits the input into the drawing of the stacks and the procedure, which
+/// are separated by a blank line
+pub fn parse(input: &str) -> Result<(Stacks, Vec<Instruction>), ParseError> {
+    match parse::blocks(Day05::DAY, input).as_slice() {
+        [stacks, procedure] => Ok((Stacks::parse(stacks)?, parse_procedure(procedure)?)),
+        [_] | [] => Err(parse::end_of_input(
+            Day05::DAY,
+            input,
+            "a blank line followed by the procedure",
+        )),
+        [_, _, extra, ..] => Err(extra[0].error(extra[0].text, "end of input")),
+    }
+}
+
+pub fn part1(stacks: &Stacks, instructions: &[Instruction]) -> Result<String, MoveError> {
+    Ok(CrateMover9000.run(stacks, instructions)?.tops())
+}
+
+pub fn part2(stacks: &Stacks, instructions: &[Instruction]) -> Result<String, MoveError> {
+    Ok(CrateMover9001.run(stacks, instructions)?.tops())
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = (Stacks, Vec<Instruction>);
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1((stacks, instructions): &Self::Input) -> Result<String, SolveError> {
+        Ok(part1(stacks, instructions)?)
+    }
+
+    fn part2((stacks, instructions): &Self::Input) -> Result<String, SolveError> {
+        Ok(part2(stacks, instructions)?)
+    }
+}
@@ -0,0 +1,314 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    error::Error,
+    fmt::Display,
+    io::{self, BufRead, Bytes},
+};
+
+use crate::{
+    error::{InputError, ParseError, ReadError},
+    solution::{Solution, SolveError},
+};
+
+pub const PACKET: usize = 4;
+pub const MESSAGE: usize = 14;
+
+/// Watches a datastream go by one character at a time, keeping count of the
+/// characters in the last `size` so that each step takes constant time
+#[derive(Debug, Clone)]
+pub struct Detector {
+    size: usize,
+    window: VecDeque<char>,
+    counts: HashMap<char, usize>,
+    /// How many characters in the window appear more than once
+    repeated: usize,
+    position: usize,
+}
+
+impl Detector {
+    /// Panics if `size` is 0
+    pub fn new(size: usize) -> Self {
+        assert!(size > 0, "a marker needs at least one character");
+        Detector {
+            size,
+            window: VecDeque::with_capacity(size + 1),
+            counts: HashMap::new(),
+            repeated: 0,
+            position: 0,
+        }
+    }
+
+    /// How many characters have been pushed so far
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Adds the next character, returning the position just past it if it
+    /// completes a window of `size` distinct characters
+    pub fn push(&mut self, c: char) -> Option<usize> {
+        self.position += 1;
+        self.window.push_back(c);
+        let count = self.counts.entry(c).or_default();
+        *count += 1;
+        if *count == 2 {
+            self.repeated += 1;
+        }
+        if self.window.len() > self.size {
+            let old = self.window.pop_front().unwrap();
+            let count = self.counts.get_mut(&old).unwrap();
+            *count -= 1;
+            if *count == 1 {
+                self.repeated -= 1;
+            }
+        }
+        (self.window.len() == self.size && self.repeated == 0).then_some(self.position)
+    }
+}
+
+/// The end of every window of distinct characters in a datastream. Positions
+/// count characters, not bytes, starting from 1.
+pub struct Markers<I> {
+    chars: I,
+    detector: Detector,
+}
+
+impl<I: Iterator<Item = char>> Iterator for Markers<I> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        self.chars.by_ref().find_map(|c| self.detector.push(c))
+    }
+}
+
+/// Panics if `size` is 0
+pub fn markers<I: IntoIterator<Item = char>>(chars: I, size: usize) -> Markers<I::IntoIter> {
+    Markers {
+        chars: chars.into_iter(),
+        detector: Detector::new(size),
+    }
+}
+
+/// Position just past the first window of `size` distinct characters
+pub fn find_marker(input: &str, size: usize) -> Option<usize> {
+    markers(input.chars(), size).next()
+}
+
+/// Decodes UTF-8 one character at a time as it's read
+pub struct Utf8Chars<R> {
+    bytes: Bytes<R>,
+}
+
+impl<R: BufRead> Utf8Chars<R> {
+    pub fn new(reader: R) -> Self {
+        Utf8Chars {
+            bytes: reader.bytes(),
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Utf8Chars<R> {
+    type Item = io::Result<char>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, "stream isn't valid UTF-8");
+        let first = match self.bytes.next()? {
+            Ok(byte) => byte,
+            Err(e) => return Some(Err(e)),
+        };
+        let len = match first.leading_ones() {
+            0 => 1,
+            n @ 2..=4 => n as usize,
+            _ => return Some(Err(invalid())),
+        };
+        let mut buf = [first, 0, 0, 0];
+        for byte in &mut buf[1..len] {
+            *byte = match self.bytes.next() {
+                Some(Ok(byte)) => byte,
+                Some(Err(e)) => return Some(Err(e)),
+                None => return Some(Err(invalid())),
+            };
+        }
+        let decoded = std::str::from_utf8(&buf[..len]).map_err(|_| invalid());
+        Some(decoded.map(|s| s.chars().next().unwrap()))
+    }
+}
+
+/// Every marker of `size` in a datastream, read without holding on to more
+/// than one window of it. Line breaks count as characters like any other.
+pub fn read_markers(reader: impl BufRead, size: usize) -> Result<Vec<usize>, ReadError> {
+    let mut detector = Detector::new(size);
+    let mut found = Vec::new();
+    for c in Utf8Chars::new(reader) {
+        let c = c.map_err(|e| ReadError::Input(InputError::Io("datastream".into(), e)))?;
+        found.extend(detector.push(c));
+    }
+    Ok(found)
+}
+
+/// What a frame's marker announces
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MarkerKind {
+    /// [`PACKET`] distinct characters
+    Packet,
+    /// [`MESSAGE`] distinct characters
+    Message,
+    /// Any other number of distinct characters
+    Custom(usize),
+}
+
+impl MarkerKind {
+    pub fn size(self) -> usize {
+        match self {
+            MarkerKind::Packet => PACKET,
+            MarkerKind::Message => MESSAGE,
+            MarkerKind::Custom(size) => size,
+        }
+    }
+}
+
+impl Display for MarkerKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MarkerKind::Packet => write!(f, "packet"),
+            MarkerKind::Message => write!(f, "message"),
+            MarkerKind::Custom(size) => write!(f, "size {size}"),
+        }
+    }
+}
+
+/// A marker and the payload following it, up to the next marker. Offsets and
+/// lengths count characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Frame {
+    /// 0-based position of the frame's first character in the datastream
+    pub offset: usize,
+    /// `None` for anything before the first marker
+    pub kind: Option<MarkerKind>,
+    pub marker: usize,
+    pub payload: usize,
+}
+
+impl Frame {
+    pub fn len(&self) -> usize {
+        self.marker + self.payload
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 0-based position of the payload's first character
+    pub fn payload_offset(&self) -> usize {
+        self.offset + self.marker
+    }
+}
+
+/// Splits a datastream into frames at each marker of one kind. Markers don't
+/// overlap: the search for the next one starts after the last one ends.
+pub struct Frames<I> {
+    chars: I,
+    kind: MarkerKind,
+    detector: Detector,
+    /// The frame being read, whose payload is still growing
+    current: Frame,
+    done: bool,
+}
+
+impl<I: Iterator<Item = char>> Iterator for Frames<I> {
+    type Item = Frame;
+
+    fn next(&mut self) -> Option<Frame> {
+        if self.done {
+            return None;
+        }
+        let size = self.kind.size();
+        loop {
+            let Some(c) = self.chars.next() else {
+                self.done = true;
+                // an empty stream doesn't even have a preamble
+                return Some(self.current).filter(|frame| !frame.is_empty());
+            };
+            self.current.payload += 1;
+            if self.detector.push(c).is_none() {
+                continue;
+            }
+            // the window just found was counted as payload so far
+            self.current.payload -= size;
+            let frame = self.current;
+            self.current = Frame {
+                offset: frame.offset + frame.len(),
+                kind: Some(self.kind),
+                marker: size,
+                payload: 0,
+            };
+            self.detector = Detector::new(size);
+            if !frame.is_empty() {
+                return Some(frame);
+            }
+        }
+    }
+}
+
+/// Panics if the kind's size is 0
+pub fn frames<I: IntoIterator<Item = char>>(chars: I, kind: MarkerKind) -> Frames<I::IntoIter> {
+    Frames {
+        chars: chars.into_iter(),
+        kind,
+        detector: Detector::new(kind.size()),
+        current: Frame {
+            offset: 0,
+            kind: None,
+            marker: 0,
+            payload: 0,
+        },
+        done: false,
+    }
+}
+
+/// A datastream without the marker a part is looking for, which includes
+/// any stream shorter than the marker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoMarker(pub MarkerKind);
+
+impl Display for NoMarker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            MarkerKind::Packet => write!(f, "no start-of-packet marker"),
+            MarkerKind::Message => write!(f, "no start-of-message marker"),
+            MarkerKind::Custom(size) => write!(f, "no marker of size {size}"),
+        }
+    }
+}
+
+impl Error for NoMarker {}
+
+pub fn part1(input: &str) -> Result<usize, NoMarker> {
+    find_marker(input, PACKET).ok_or(NoMarker(MarkerKind::Packet))
+}
+
+pub fn part2(input: &str) -> Result<usize, NoMarker> {
+    find_marker(input, MESSAGE).ok_or(NoMarker(MarkerKind::Message))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // the line break at the end of the file isn't part of the datastream
+        Ok(input.trim_end_matches(['\r', '\n']).to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part1(input)?)
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part2(input)?)
+    }
+}
@@ -0,0 +1,429 @@
+use std::{collections::BTreeMap, error::Error, fmt::Display};
+
+use crate::{
+    error::ParseError,
+    parse::{self, Line},
+    solution::{Solution, SolveError},
+};
+
+pub const TOTAL_FS_SIZE: usize = 70_000_000;
+pub const REQUIRED_SPACE: usize = 30_000_000;
+
+/// An index into a [`FileSystem`]'s nodes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Kind {
+    /// Children by name, so listings come out sorted
+    Dir(BTreeMap<String, NodeId>),
+    File,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub name: String,
+    pub parent: Option<NodeId>,
+    /// A file's own size, or everything under a directory
+    pub size: usize,
+    pub kind: Kind,
+}
+
+impl Node {
+    pub fn is_dir(&self) -> bool {
+        matches!(self.kind, Kind::Dir(_))
+    }
+}
+
+/// A node found while walking the tree, and how far below the start of the
+/// walk it is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry<'a> {
+    pub id: NodeId,
+    pub depth: usize,
+    pub node: &'a Node,
+}
+
+/// A directory tree rooted at `/`, with every directory's total size kept up
+/// to date as files are added
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSystem {
+    nodes: Vec<Node>,
+}
+
+/// Why a node couldn't be added
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddError {
+    /// The parent is a file
+    NotADir,
+    /// The name is taken by a node of the other kind
+    Taken,
+}
+
+impl FileSystem {
+    pub const ROOT: NodeId = NodeId(0);
+
+    pub fn new() -> Self {
+        let root = Node {
+            name: String::new(),
+            parent: None,
+            size: 0,
+            kind: Kind::Dir(BTreeMap::new()),
+        };
+        FileSystem { nodes: vec![root] }
+    }
+
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id.0]
+    }
+
+    pub fn child(&self, dir: NodeId, name: &str) -> Option<NodeId> {
+        match &self.node(dir).kind {
+            Kind::Dir(children) => children.get(name).copied(),
+            Kind::File => None,
+        }
+    }
+
+    /// The directory called `name` in `parent`, created if it isn't there yet
+    pub fn mkdir(&mut self, parent: NodeId, name: &str) -> Result<NodeId, AddError> {
+        match self.child(parent, name) {
+            Some(id) if self.node(id).is_dir() => Ok(id),
+            Some(_) => Err(AddError::Taken),
+            None => self.add(parent, name, 0, Kind::Dir(BTreeMap::new())),
+        }
+    }
+
+    /// Adds a file to `parent`, or changes its size if it's already there
+    pub fn create_file(
+        &mut self,
+        parent: NodeId,
+        name: &str,
+        size: usize,
+    ) -> Result<NodeId, AddError> {
+        let id = match self.child(parent, name) {
+            Some(id) if self.node(id).is_dir() => return Err(AddError::Taken),
+            Some(id) => id,
+            None => self.add(parent, name, 0, Kind::File)?,
+        };
+        let old = self.node(id).size;
+        let mut at = Some(id);
+        while let Some(node) = at {
+            let node = &mut self.nodes[node.0];
+            node.size = node.size - old + size;
+            at = node.parent;
+        }
+        Ok(id)
+    }
+
+    fn add(
+        &mut self,
+        parent: NodeId,
+        name: &str,
+        size: usize,
+        kind: Kind,
+    ) -> Result<NodeId, AddError> {
+        let id = NodeId(self.nodes.len());
+        match &mut self.nodes[parent.0].kind {
+            Kind::Dir(children) => children.insert(name.to_string(), id),
+            Kind::File => return Err(AddError::NotADir),
+        };
+        self.nodes.push(Node {
+            name: name.to_string(),
+            parent: Some(parent),
+            size,
+            kind,
+        });
+        Ok(id)
+    }
+
+    /// The node at an absolute path like `/a/e`. Empty components, as in `/a/`
+    /// or `//a`, are skipped.
+    pub fn lookup(&self, path: &str) -> Option<NodeId> {
+        let rest = path.strip_prefix('/')?;
+        rest.split('/')
+            .filter(|name| !name.is_empty())
+            .try_fold(Self::ROOT, |dir, name| self.child(dir, name))
+    }
+
+    /// The absolute path of a node, with a trailing `/` for directories
+    pub fn path(&self, id: NodeId) -> String {
+        let mut names = Vec::new();
+        let mut at = id;
+        while let Some(parent) = self.node(at).parent {
+            names.push(self.node(at).name.as_str());
+            at = parent;
+        }
+        names.reverse();
+        let mut path = format!("/{}", names.join("/"));
+        if self.node(id).is_dir() && id != Self::ROOT {
+            path.push('/');
+        }
+        path
+    }
+
+    /// Everything under `from`, itself included, depth first with children in
+    /// name order
+    pub fn walk(&self, from: NodeId) -> Vec<Entry<'_>> {
+        let mut entries = Vec::new();
+        let mut stack = vec![(from, 0)];
+        while let Some((id, depth)) = stack.pop() {
+            let node = self.node(id);
+            entries.push(Entry { id, depth, node });
+            if let Kind::Dir(children) = &node.kind {
+                stack.extend(children.values().rev().map(|&child| (child, depth + 1)));
+            }
+        }
+        entries
+    }
+
+    /// Every node in the tree with its absolute path
+    pub fn entries(&self) -> impl Iterator<Item = (String, Entry<'_>)> {
+        self.walk(Self::ROOT)
+            .into_iter()
+            .map(|entry| (self.path(entry.id), entry))
+    }
+
+    /// Total size of every directory under `from`, deepest first like `du`
+    pub fn du(&self, from: NodeId) -> Vec<(String, usize)> {
+        let mut dirs: Vec<_> = self
+            .walk(from)
+            .into_iter()
+            .filter(|entry| entry.node.is_dir())
+            .collect();
+        dirs.sort_by_key(|entry| std::cmp::Reverse(entry.depth));
+        dirs.into_iter()
+            .map(|entry| (self.path(entry.id), entry.node.size))
+            .collect()
+    }
+
+    pub fn find(&self, predicate: impl Fn(&Entry) -> bool) -> Vec<Entry<'_>> {
+        self.walk(Self::ROOT)
+            .into_iter()
+            .filter(|entry| predicate(entry))
+            .collect()
+    }
+
+    /// Nodes whose name matches a pattern where `*` stands for any run of
+    /// characters and `?` for any one
+    pub fn find_name(&self, pattern: &str) -> Vec<Entry<'_>> {
+        self.find(|entry| glob(pattern, &entry.node.name))
+    }
+
+    /// Directories whose total size passes `predicate`
+    pub fn find_dirs(&self, predicate: impl Fn(usize) -> bool) -> Vec<Entry<'_>> {
+        self.find(|entry| entry.node.is_dir() && predicate(entry.node.size))
+    }
+
+    /// The tree under `from` drawn like `tree`, with sizes like `du -h`
+    pub fn tree(&self, from: NodeId) -> String {
+        let mut lines = Vec::new();
+        // whether each ancestor of the current entry was the last child
+        let mut last = Vec::new();
+        for entry in self.walk(from) {
+            last.truncate(entry.depth);
+            let is_last = match entry.node.parent {
+                Some(parent) if entry.id != from => match &self.node(parent).kind {
+                    Kind::Dir(children) => children.values().last() == Some(&entry.id),
+                    Kind::File => unreachable!("files have no children"),
+                },
+                _ => true,
+            };
+            let mut line = String::new();
+            for &done in last.iter().skip(1) {
+                line.push_str(if done { "    " } else { "│   " });
+            }
+            if entry.depth > 0 {
+                line.push_str(if is_last { "└── " } else { "├── " });
+            }
+            let name = match entry.id == Self::ROOT {
+                true => "/".to_string(),
+                false if entry.node.is_dir() => format!("{}/", entry.node.name),
+                false => entry.node.name.clone(),
+            };
+            line.push_str(&format!("{name} ({})", human_size(entry.node.size)));
+            lines.push(line);
+            last.push(is_last);
+        }
+        lines.join("\n")
+    }
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        FileSystem::new()
+    }
+}
+
+/// A size in bytes the way `du -h` writes it, e.g. `584B`, `2.5K` or `47M`.
+/// Like `du`, this rounds up, so a size is never shown smaller than it is.
+pub fn human_size(bytes: usize) -> String {
+    const UNITS: [&str; 5] = ["B", "K", "M", "G", "T"];
+    let bytes = bytes as u128;
+    let mut unit = 0;
+    while unit < UNITS.len() - 1 && bytes >= 1024u128.pow(unit as u32 + 1) {
+        unit += 1;
+    }
+    let scale = 1024u128.pow(unit as u32);
+    let tenths = (bytes * 10).div_ceil(scale);
+    let whole = bytes.div_ceil(scale);
+    if unit == 0 {
+        format!("{bytes}B")
+    } else if tenths < 100 {
+        format!("{}.{}{}", tenths / 10, tenths % 10, UNITS[unit])
+    } else if whole == 1024 && unit < UNITS.len() - 1 {
+        // rounding up reached the next unit
+        format!("1.0{}", UNITS[unit + 1])
+    } else {
+        format!("{whole}{}", UNITS[unit])
+    }
+}
+
+/// Whether `name` matches `pattern`, where `*` matches any run of characters
+/// and `?` any single one
+pub fn glob(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<_> = pattern.chars().collect();
+    let name: Vec<_> = name.chars().collect();
+    // matches[j]: whether the pattern so far matches the first j characters
+    let mut matches = vec![false; name.len() + 1];
+    matches[0] = true;
+    for p in pattern {
+        let previous = std::mem::take(&mut matches);
+        matches = vec![false; name.len() + 1];
+        for j in 0..=name.len() {
+            matches[j] = match p {
+                '*' => previous[j] || (j > 0 && matches[j - 1]),
+                '?' => j > 0 && previous[j - 1],
+                c => j > 0 && previous[j - 1] && name[j - 1] == c,
+            };
+        }
+    }
+    matches[name.len()]
+}
+
+#[derive(Debug)]
+pub enum Cmd<'a> {
+    Cd(&'a str),
+    Ls,
+    FileDescriptor(usize, &'a str),
+    DirName(&'a str),
+}
+
+impl<'a> Cmd<'a> {
+    pub fn parse(line: Line<'a>) -> Result<Self, ParseError> {
+        let mut tokens = line.tokens();
+        let cmd = match tokens.next("a command or a directory listing")? {
+            "$" => match tokens.next("cd or ls")? {
+                "ls" => Cmd::Ls,
+                "cd" => Cmd::Cd(tokens.next("a directory name")?),
+                cmd => return Err(line.error(cmd, "cd or ls")),
+            },
+            "dir" => Cmd::DirName(tokens.next("a directory name")?),
+            size => {
+                let size = line.parse(size, "$, dir or a file size")?;
+                Cmd::FileDescriptor(size, tokens.next("a file name")?)
+            }
+        };
+        tokens.end()?;
+        Ok(cmd)
+    }
+}
+
+/// Rebuilds the file system from a terminal session, which starts in `/`.
+/// Directories can be visited and listed more than once.
+pub fn parse(input: &str) -> Result<FileSystem, ParseError> {
+    let mut fs = FileSystem::new();
+    let mut cwd = FileSystem::ROOT;
+    for line in parse::lines(Day07::DAY, input) {
+        let (name, added) = match Cmd::parse(line)? {
+            Cmd::Ls => continue,
+            Cmd::Cd("/") => {
+                cwd = FileSystem::ROOT;
+                continue;
+            }
+            Cmd::Cd("..") => match fs.node(cwd).parent {
+                Some(parent) => {
+                    cwd = parent;
+                    continue;
+                }
+                None => return Err(line.error(line.text, "a directory below /")),
+            },
+            Cmd::Cd(name) => {
+                let dir = fs.mkdir(cwd, name);
+                if let Ok(dir) = dir {
+                    cwd = dir;
+                }
+                (name, dir)
+            }
+            Cmd::DirName(name) => (name, fs.mkdir(cwd, name)),
+            Cmd::FileDescriptor(size, name) => (name, fs.create_file(cwd, name, size)),
+        };
+        if added.is_err() {
+            return Err(line.error(name, "a name not already used for something else"));
+        }
+    }
+    Ok(fs)
+}
+
+pub fn part1(fs: &FileSystem) -> usize {
+    fs.find_dirs(|size| size <= 100_000)
+        .iter()
+        .map(|entry| entry.node.size)
+        .sum()
+}
+
+/// A file system holding more than fits on the disk
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiskTooSmall {
+    pub used: usize,
+}
+
+impl Display for DiskTooSmall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the files take up {} bytes, more than the disk's {TOTAL_FS_SIZE}",
+            self.used
+        )
+    }
+}
+
+impl Error for DiskTooSmall {}
+
+/// Size of the smallest directory that frees up enough space when deleted.
+/// There's always one as long as the files fit on the disk, since deleting
+/// the root frees up all of it.
+pub fn part2(fs: &FileSystem) -> Result<usize, DiskTooSmall> {
+    let used = fs.node(FileSystem::ROOT).size;
+    let available = TOTAL_FS_SIZE
+        .checked_sub(used)
+        .ok_or(DiskTooSmall { used })?;
+    let needed = REQUIRED_SPACE.saturating_sub(available);
+    Ok(fs
+        .find_dirs(|size| size >= needed)
+        .iter()
+        .map(|entry| entry.node.size)
+        .min()
+        .unwrap())
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = FileSystem;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(fs: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part1(fs))
+    }
+
+    fn part2(fs: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part2(fs)?)
+    }
+}
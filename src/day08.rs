@@ -0,0 +1,86 @@
+use crate::{
+    error::ParseError,
+    grid::{Coord, Dense, Grid},
+    solution::{Solution, SolveError},
+};
+
+pub struct Forest {
+    pub trees: Dense<u8>,
+}
+
+impl Forest {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let trees = Dense::parse(Day08::DAY, input, |_, c| {
+            c.to_digit(10)
+                .map(|height| height as u8)
+                .ok_or("a tree height (0-9)")
+        })?;
+        Ok(Forest { trees })
+    }
+
+    /// Whether a tree is visible from outside the forest, and its scenic score
+    pub fn eval(&self, tree: Coord) -> (bool, u32) {
+        Coord::NEIGHBORS_4
+            .iter()
+            .map(|&direction| self.eval_for(tree, direction))
+            .reduce(|(a_visible, a_score), (b_visible, b_score)| {
+                (a_visible || b_visible, a_score * b_score)
+            })
+            .unwrap()
+    }
+
+    fn eval_for(&self, tree: Coord, direction: Coord) -> (bool, u32) {
+        let mut count = 0;
+        let current = self.trees.get(tree).unwrap();
+        for (_, height) in self.trees.ray(tree, direction) {
+            count += 1;
+            if height >= current {
+                return (false, count);
+            }
+        }
+        (true, count)
+    }
+
+    pub fn evaluate(&self) -> Dense<(bool, u32)> {
+        self.trees.map(|tree, _| self.eval(tree))
+    }
+}
+
+pub fn part1(forest: &Forest) -> u32 {
+    forest
+        .evaluate()
+        .iter()
+        .filter(|(_, (visible, _))| *visible)
+        .count() as u32
+}
+
+pub fn part2(forest: &Forest) -> u32 {
+    forest
+        .evaluate()
+        .iter()
+        .map(|(_, (_, score))| *score)
+        .max()
+        .unwrap()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Forest;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Forest::parse(input)
+    }
+
+    fn part1(forest: &Self::Input) -> Result<u32, SolveError> {
+        Ok(part1(forest))
+    }
+
+    fn part2(forest: &Self::Input) -> Result<u32, SolveError> {
+        Ok(part2(forest))
+    }
+}
@@ -0,0 +1,156 @@
+use std::collections::HashSet;
+
+use crate::{
+    error::ParseError,
+    parse::{self, Line},
+    point::Point2,
+    solution::{Solution, SolveError},
+};
+
+pub type Knot = Point2<i32>;
+
+#[derive(Debug)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub fn delta(&self) -> Knot {
+        match self {
+            Direction::Up => Knot::UNIT_Y,
+            Direction::Down => -Knot::UNIT_Y,
+            Direction::Left => -Knot::UNIT_X,
+            Direction::Right => Knot::UNIT_X,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    pub direction: Direction,
+    pub steps: u32,
+}
+
+impl Instruction {
+    pub fn parse(line: Line) -> Result<Self, ParseError> {
+        let mut tokens = line.tokens();
+        let direction = match tokens.next("a direction (U, D, L or R)")? {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            token => return Err(line.error(token, "a direction (U, D, L or R)")),
+        };
+        let steps = tokens.parse("a number of steps")?;
+        tokens.end()?;
+        Ok(Self { direction, steps })
+    }
+}
+
+/// Where a knot ends up after its leader has moved. Knots that are no longer
+/// touching take a single step, diagonally if need be, straight towards it.
+pub fn follow(knot: Knot, leader: Knot) -> Knot {
+    if knot.chebyshev(leader) < 2 {
+        knot
+    } else {
+        knot + (leader - knot).signum()
+    }
+}
+
+#[derive(Debug)]
+pub struct Rope(pub Vec<Knot>);
+
+impl Rope {
+    pub fn new(n: usize) -> Self {
+        Self(vec![Knot::default(); n])
+    }
+
+    pub fn update(&mut self, direction: &Direction) {
+        self.0[0] += direction.delta();
+        for i in 1..self.0.len() {
+            // apparently having a windows_mut() method is once again something
+            // that requires GATs because it depends on a Lending Iterator...
+            if let [prev, current] = self.0[(i - 1)..=i].as_mut() {
+                *current = follow(*current, *prev);
+            }
+        }
+    }
+
+    pub fn tail(&self) -> Knot {
+        *self.0.last().unwrap()
+    }
+}
+
+#[derive(Debug)]
+pub struct Grid {
+    rope: Rope,
+    visited: HashSet<Knot>,
+}
+
+impl Grid {
+    pub fn new(n: usize) -> Self {
+        Grid {
+            rope: Rope::new(n),
+            visited: HashSet::new(),
+        }
+    }
+
+    pub fn update(&mut self, instruction: &Instruction) {
+        for _ in 0..instruction.steps {
+            self.rope.update(&instruction.direction);
+            self.visited.insert(self.rope.tail());
+        }
+    }
+
+    pub fn visited(&self) -> usize {
+        self.visited.len()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse::lines(Day09::DAY, input)
+        .map(Instruction::parse)
+        .collect()
+}
+
+/// Number of positions visited by the tail of a rope with `n` knots
+pub fn simulate(instructions: &[Instruction], n: usize) -> usize {
+    let mut grid = Grid::new(n);
+    for instruction in instructions {
+        grid.update(instruction);
+    }
+    grid.visited()
+}
+
+pub fn part1(instructions: &[Instruction]) -> usize {
+    simulate(instructions, 2)
+}
+
+pub fn part2(instructions: &[Instruction]) -> usize {
+    simulate(instructions, 10)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Instruction>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part1(instructions))
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part2(instructions))
+    }
+}
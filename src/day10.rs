@@ -0,0 +1,93 @@
+use crate::{
+    error::ParseError,
+    parse::{self, Line},
+    solution::{Solution, SolveError},
+};
+
+#[derive(Debug)]
+pub enum Instruction {
+    Noop,
+    AddX(i32),
+}
+
+impl Instruction {
+    pub fn parse(line: Line) -> Result<Self, ParseError> {
+        let mut tokens = line.tokens();
+        let instruction = match tokens.next("noop or addx")? {
+            "noop" => Instruction::Noop,
+            "addx" => Instruction::AddX(tokens.parse("an integer")?),
+            token => return Err(line.error(token, "noop or addx")),
+        };
+        tokens.end()?;
+        Ok(instruction)
+    }
+
+    pub fn eval(&self, history: &mut Vec<i32>) {
+        let last = *history.last().unwrap();
+        match self {
+            Instruction::Noop => history.push(last),
+            Instruction::AddX(x) => {
+                history.push(last);
+                history.push(last + x);
+            }
+        }
+    }
+}
+
+/// The value of the X register during every cycle
+pub fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    let mut history = vec![1];
+    for line in parse::lines(Day10::DAY, input) {
+        Instruction::parse(line)?.eval(&mut history);
+    }
+    Ok(history)
+}
+
+/// Sum of the signal strengths, where cycles after the program has finished
+/// don't count
+pub fn part1(history: &[i32]) -> i32 {
+    let checks = [20, 60, 100, 140, 180, 220];
+    checks
+        .iter()
+        .filter_map(|&i| history.get(i - 1).map(|x| i as i32 * x))
+        .sum()
+}
+
+/// Renders the CRT, one scan line per row
+pub fn part2(history: &[i32]) -> String {
+    let mut display = [' '; 240];
+    // the screen stays dark once the program has finished
+    for (i, (pixel, &sprite_center)) in display.iter_mut().zip(history).enumerate() {
+        let i = i as i32 % 40;
+        if sprite_center - 1 == i || sprite_center == i || sprite_center + 1 == i {
+            *pixel = '#';
+        }
+    }
+    display
+        .chunks(40)
+        .map(|pixels| pixels.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(history: &Self::Input) -> Result<i32, SolveError> {
+        Ok(part1(history))
+    }
+
+    fn part2(history: &Self::Input) -> Result<String, SolveError> {
+        Ok(part2(history))
+    }
+}
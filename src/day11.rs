@@ -0,0 +1,276 @@
+use std::collections::VecDeque;
+
+use crate::{
+    error::ParseError,
+    parse::{self, Line},
+    solution::{Solution, SolveError},
+};
+
+const ARG: &str = "old or a number";
+const ACTION: &str = "+ or *";
+
+#[derive(Debug, Clone)]
+pub struct Test {
+    pub divisible_by: u64,
+    pub on_true: usize,
+    pub on_false: usize,
+}
+
+impl Test {
+    /// Parses the three lines starting with `Test: divisible by N`
+    pub fn parse(condition: Line, on_true: Line, on_false: Line) -> Result<Self, ParseError> {
+        let mut tokens = condition.tokens();
+        for keyword in ["Test:", "divisible", "by"] {
+            tokens.keyword(keyword)?;
+        }
+        let token = tokens.next("a divisor")?;
+        let divisible_by = match condition.parse(token, "a divisor")? {
+            0 => return Err(condition.error(token, "a non-zero divisor")),
+            n => n,
+        };
+        tokens.end()?;
+        Ok(Test {
+            divisible_by,
+            on_true: Self::parse_throw(on_true, "true:")?,
+            on_false: Self::parse_throw(on_false, "false:")?,
+        })
+    }
+
+    fn parse_throw(line: Line, condition: &str) -> Result<usize, ParseError> {
+        let mut tokens = line.tokens();
+        for keyword in ["If", condition, "throw", "to", "monkey"] {
+            tokens.keyword(keyword)?;
+        }
+        let recipient = tokens.parse("a monkey number")?;
+        tokens.end()?;
+        Ok(recipient)
+    }
+
+    pub fn eval(&self, val: u64) -> usize {
+        if val.is_multiple_of(self.divisible_by) {
+            self.on_true
+        } else {
+            self.on_false
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Action {
+    Add,
+    Mul,
+}
+
+impl Action {
+    pub fn parse(input: &str) -> Option<Self> {
+        match input {
+            "+" => Some(Self::Add),
+            "*" => Some(Self::Mul),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Arg {
+    Old,
+    Const(u64),
+}
+
+impl Arg {
+    pub fn parse(val: &str) -> Option<Self> {
+        match val {
+            "old" => Some(Arg::Old),
+            _ => val.parse().ok().map(Arg::Const),
+        }
+    }
+
+    pub fn val(&self, old: u64) -> u64 {
+        match self {
+            Arg::Old => old,
+            Arg::Const(x) => *x,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Op {
+    pub a: Arg,
+    pub b: Arg,
+    pub action: Action,
+}
+
+impl Op {
+    /// Parses a line like `Operation: new = old * 19`
+    pub fn parse(line: Line) -> Result<Self, ParseError> {
+        let mut tokens = line.tokens();
+        for keyword in ["Operation:", "new", "="] {
+            tokens.keyword(keyword)?;
+        }
+        let token = tokens.next(ARG)?;
+        let a = Arg::parse(token).ok_or_else(|| line.error(token, ARG))?;
+        let token = tokens.next(ACTION)?;
+        let action = Action::parse(token).ok_or_else(|| line.error(token, ACTION))?;
+        let token = tokens.next(ARG)?;
+        let b = Arg::parse(token).ok_or_else(|| line.error(token, ARG))?;
+        tokens.end()?;
+        Ok(Self { a, b, action })
+    }
+
+    pub fn eval(&self, old: u64) -> u64 {
+        let (a, b) = (self.a.val(old), self.b.val(old));
+        match self.action {
+            Action::Add => a + b,
+            Action::Mul => a * b,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    pub items: VecDeque<u64>,
+    pub op: Op,
+    pub test: Test,
+    pub total_inspected: u64,
+}
+
+impl Monkey {
+    /// Parses the six lines describing a monkey
+    pub fn parse(block: &[Line]) -> Result<Self, ParseError> {
+        let line = |i: usize, expected: &str| {
+            let last = block[block.len() - 1];
+            block
+                .get(i)
+                .copied()
+                .ok_or_else(|| last.error(last.end(), format!("a line starting with {expected}")))
+        };
+
+        let header = line(0, "Monkey")?;
+        let mut tokens = header.tokens();
+        tokens.keyword("Monkey")?;
+        tokens.next("a monkey number")?;
+        tokens.end()?;
+
+        let starting_items = line(1, "Starting items:")?;
+        let (label, list) = starting_items.split_once(":", "Starting items:")?;
+        if label.trim() != "Starting items" {
+            return Err(starting_items.error(label.trim(), "Starting items:"));
+        }
+        let items = list
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(|item| starting_items.parse(item, "a worry level"))
+            .collect::<Result<_, _>>()?;
+
+        let op = Op::parse(line(2, "Operation:")?)?;
+        let test = Test::parse(
+            line(3, "Test:")?,
+            line(4, "If true:")?,
+            line(5, "If false:")?,
+        )?;
+        if let Some(extra) = block.get(6) {
+            return Err(extra.error(extra.text.trim(), "a blank line before the next monkey"));
+        }
+        Ok(Self {
+            items,
+            op,
+            test,
+            total_inspected: 0,
+        })
+    }
+
+    pub fn eval_next(&mut self, worry_reduction: bool, ceil: u64) -> Option<(u64, usize)> {
+        if let Some(item) = self.items.pop_front() {
+            self.total_inspected += 1;
+            let mut scored = self.op.eval(item) % ceil;
+            if worry_reduction {
+                scored /= 3;
+            }
+            let recipient = self.test.eval(scored);
+            Some((scored, recipient))
+        } else {
+            None
+        }
+    }
+
+    pub fn receive(&mut self, item: u64) {
+        self.items.push_back(item);
+    }
+}
+
+pub fn round(monkeys: &mut [Monkey], worry_reduction: bool, ceil: u64) {
+    for i in 0..monkeys.len() {
+        while let Some((item, recipient)) = monkeys[i].eval_next(worry_reduction, ceil) {
+            monkeys[recipient].receive(item);
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let blocks = parse::blocks(Day11::DAY, input);
+    let monkeys = blocks
+        .iter()
+        .map(|block| Monkey::parse(block))
+        .collect::<Result<Vec<_>, _>>()?;
+    if monkeys.is_empty() {
+        return Err(parse::end_of_input(Day11::DAY, input, "a monkey"));
+    }
+    // every monkey has to throw to one that exists
+    for (monkey, block) in monkeys.iter().zip(&blocks) {
+        let recipients = [
+            (monkey.test.on_true, block[4]),
+            (monkey.test.on_false, block[5]),
+        ];
+        for (recipient, line) in recipients {
+            if recipient >= monkeys.len() {
+                let token = line.text.split_whitespace().last().unwrap();
+                let expected = format!("a monkey number below {}", monkeys.len());
+                return Err(line.error(token, expected));
+            }
+        }
+    }
+    Ok(monkeys)
+}
+
+/// Product of the two highest inspection counts after the given number of rounds
+pub fn monkey_business(monkeys: &[Monkey], rounds: usize, worry_reduction: bool) -> u64 {
+    let mut monkeys = monkeys.to_vec();
+    let ceil = monkeys.iter().map(|m| m.test.divisible_by).product();
+    for _ in 0..rounds {
+        round(&mut monkeys, worry_reduction, ceil);
+    }
+    let mut activity: Vec<_> = monkeys.iter().map(|m| m.total_inspected).collect();
+    activity.sort_unstable_by(|a, b| b.cmp(a));
+    activity.iter().take(2).product()
+}
+
+pub fn part1(monkeys: &[Monkey]) -> u64 {
+    monkey_business(monkeys, 20, true)
+}
+
+pub fn part2(monkeys: &[Monkey]) -> u64 {
+    monkey_business(monkeys, 10_000, false)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<Monkey>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(monkeys: &Self::Input) -> Result<u64, SolveError> {
+        Ok(part1(monkeys))
+    }
+
+    fn part2(monkeys: &Self::Input) -> Result<u64, SolveError> {
+        Ok(part2(monkeys))
+    }
+}
@@ -0,0 +1,125 @@
+use std::{error::Error, fmt::Display};
+
+use pathfinding::prelude::bfs;
+
+use crate::{
+    error::ParseError,
+    grid::{Coord, Dense, Grid},
+    parse,
+    solution::{Solution, SolveError},
+};
+
+pub struct Map {
+    pub start: Coord,
+    pub end: Coord,
+    pub elevations: Dense<u32>,
+}
+
+impl Map {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut start = None;
+        let mut end = None;
+        let elevations = Dense::parse(Day12::DAY, input, |at, c| {
+            let marker = match c {
+                'S' => Some(&mut start),
+                'E' => Some(&mut end),
+                _ => None,
+            };
+            if let Some(marker) = marker {
+                if marker.is_some() {
+                    return Err("only one S and one E");
+                }
+                *marker = Some(at);
+            }
+            match c {
+                'S' => Ok(0),
+                'E' => Ok(25),
+                'a'..='z' => Ok(c as u32 - 'a' as u32),
+                _ => Err("an elevation (a-z), S or E"),
+            }
+        })?;
+        let missing = |marker| parse::end_of_input(Day12::DAY, input, marker);
+        Ok(Self {
+            start: start.ok_or_else(|| missing("a start position (S)"))?,
+            end: end.ok_or_else(|| missing("a best signal position (E)"))?,
+            elevations,
+        })
+    }
+
+    pub fn successors(&self, &at: &Coord) -> Vec<Coord> {
+        let elevation = match self.elevations.get(at) {
+            Some(e) => e,
+            None => return Vec::new(),
+        };
+        self.elevations
+            .neighbors4(at)
+            .filter(|(_, val)| **val <= elevation + 1)
+            .map(|(position, _)| position)
+            .collect()
+    }
+}
+
+/// Where the climb to E can't start from without a step up of more than one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoPath {
+    /// The start position, S
+    Start,
+    /// Any square at elevation a
+    Lowest,
+}
+
+impl Display for NoPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NoPath::Start => write!(f, "no path from S to E"),
+            NoPath::Lowest => write!(f, "no path from any square at elevation a to E"),
+        }
+    }
+}
+
+impl Error for NoPath {}
+
+pub fn part1(map: &Map) -> Result<usize, NoPath> {
+    let path = bfs(&map.start, |p| map.successors(p), |n| *n == map.end).ok_or(NoPath::Start)?;
+    Ok(path.len() - 1)
+}
+
+pub fn part2(map: &Map) -> Result<usize, NoPath> {
+    let starting_candidates: Vec<_> = map
+        .elevations
+        .iter()
+        .filter_map(|(position, elevation)| match elevation {
+            0 => Some(position),
+            _ => None,
+        })
+        .collect();
+    let ans = starting_candidates
+        .iter()
+        .filter_map(|start| bfs(start, |p| map.successors(p), |n| *n == map.end))
+        .map(|path| path.len())
+        .min()
+        .ok_or(NoPath::Lowest)?;
+    Ok(ans - 1)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Map::parse(input)
+    }
+
+    fn part1(map: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part1(map)?)
+    }
+
+    fn part2(map: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part2(map)?)
+    }
+}
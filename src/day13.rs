@@ -0,0 +1,190 @@
+use std::{cmp::Ordering, iter::Peekable, str::CharIndices};
+
+use itertools::EitherOrBoth::{Both, Left, Right};
+use itertools::Itertools;
+
+use crate::{
+    error::ParseError,
+    parse::{self, Line},
+    solution::{Solution, SolveError},
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Packet {
+    Number(i32),
+    List(Vec<Packet>),
+}
+
+type Chars<'a> = Peekable<CharIndices<'a>>;
+
+impl Packet {
+    pub fn parse(line: Line) -> Result<Self, ParseError> {
+        let mut chars = line.text.char_indices().peekable();
+        let packet = Self::parse_list(line, &mut chars)?;
+        match chars.next() {
+            Some((i, _)) => Err(line.error(&line.text[i..], "end of packet")),
+            None => Ok(packet),
+        }
+    }
+
+    fn parse_list(line: Line, chars: &mut Chars) -> Result<Self, ParseError> {
+        match chars.next() {
+            Some((_, '[')) => {}
+            Some((i, _)) => return Err(line.error(line.char_at(i), "'['")),
+            None => return Err(line.error(line.end(), "'['")),
+        }
+        let mut list = Vec::new();
+        if let Some((_, ']')) = chars.peek() {
+            chars.next();
+            return Ok(Self::List(list));
+        }
+        loop {
+            list.push(Self::parse_item(line, chars)?);
+            match chars.next() {
+                Some((_, ',')) => continue,
+                Some((_, ']')) => return Ok(Self::List(list)),
+                Some((i, _)) => return Err(line.error(line.char_at(i), "',' or ']'")),
+                None => return Err(line.error(line.end(), "',' or ']'")),
+            }
+        }
+    }
+
+    fn parse_item(line: Line, chars: &mut Chars) -> Result<Self, ParseError> {
+        let start = match chars.peek() {
+            Some((_, '[')) => return Self::parse_list(line, chars),
+            Some(&(i, c)) if c.is_ascii_digit() => i,
+            Some(&(i, _)) => return Err(line.error(line.char_at(i), "a number or '['")),
+            None => return Err(line.error(line.end(), "a number or '['")),
+        };
+        let mut end = start;
+        while let Some(&(i, c)) = chars.peek() {
+            if !c.is_ascii_digit() {
+                break;
+            }
+            end = i + 1;
+            chars.next();
+        }
+        let number = line.parse(&line.text[start..end], "a number")?;
+        Ok(Self::Number(number))
+    }
+
+    /// The divider packet `[[n]]`
+    pub fn divider(n: i32) -> Self {
+        Packet::List(vec![Packet::List(vec![Packet::Number(n)])])
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        match self {
+            Packet::Number(left) => match rhs {
+                Packet::Number(right) => left.cmp(right),
+                Packet::List(_) => {
+                    let lhs = Packet::List(vec![Packet::Number(*left)]);
+                    lhs.cmp(rhs)
+                }
+            },
+            Packet::List(left) => match rhs {
+                Packet::Number(right) => {
+                    let right = Packet::List(vec![Packet::Number(*right)]);
+                    self.cmp(&right)
+                }
+                Packet::List(right) => {
+                    for pair in left.iter().zip_longest(right.iter()) {
+                        match pair {
+                            Left(_) => return Ordering::Greater,
+                            Right(_) => return Ordering::Less,
+                            Both(left, right) => match left.cmp(right) {
+                                Ordering::Equal => continue,
+                                ordering => return ordering,
+                            },
+                        }
+                    }
+                    Ordering::Equal
+                }
+            },
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Every packet in the input, in order, ignoring the blank lines between pairs
+pub fn parse(input: &str) -> Result<Vec<Packet>, ParseError> {
+    let mut packets = Vec::new();
+    for pair in parse::blocks(Day13::DAY, input) {
+        match pair.as_slice() {
+            [left, right] => {
+                packets.push(Packet::parse(*left)?);
+                packets.push(Packet::parse(*right)?);
+            }
+            [only] => return Err(only.error(only.end(), "a second packet on the next line")),
+            [_, _, extra, ..] => {
+                return Err(extra.error(extra.text, "a blank line between pairs"));
+            }
+            [] => unreachable!(),
+        }
+    }
+    Ok(packets)
+}
+
+pub fn find(packets: &[Packet], x: &Packet) -> usize {
+    packets
+        .iter()
+        .enumerate()
+        .filter_map(|(i, packet)| match x == packet {
+            true => Some(i + 1),
+            false => None,
+        })
+        .next()
+        .unwrap()
+}
+
+pub fn part1(packets: &[Packet]) -> u32 {
+    packets
+        .chunks(2)
+        .enumerate()
+        .filter_map(|(i, pair)| match pair[0].cmp(&pair[1]) {
+            Ordering::Less => Some(i as u32 + 1),
+            _ => None,
+        })
+        .sum()
+}
+
+pub fn part2(packets: &[Packet]) -> usize {
+    let mut packets = packets.to_vec();
+    let div1 = Packet::divider(2);
+    let div2 = Packet::divider(6);
+    packets.push(div1.clone());
+    packets.push(div2.clone());
+    packets.sort_unstable();
+    let a = find(&packets, &div1);
+    let b = find(&packets, &div2);
+    a * b
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Packet>;
+    type Part1 = u32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(packets: &Self::Input) -> Result<u32, SolveError> {
+        Ok(part1(packets))
+    }
+
+    fn part2(packets: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part2(packets))
+    }
+}
@@ -0,0 +1,210 @@
+use std::fmt::Display;
+
+use crate::{
+    error::ParseError,
+    grid::{Bounds, Coord, Grid, Sparse},
+    parse::{self, Line},
+    solution::{Solution, SolveError},
+};
+
+/// Where sand tries to fall, in order of preference
+const DOWN: Coord = Coord::new(0, 1);
+const DOWN_LEFT: Coord = Coord::new(-1, 1);
+const DOWN_RIGHT: Coord = Coord::new(1, 1);
+
+#[derive(Debug, Clone)]
+pub enum Rock {
+    Stone,
+    Sand,
+}
+
+/// Every point on the straight line from `a` to `b`, which must share a row or
+/// a column
+fn draw(a: Coord, b: Coord) -> impl Iterator<Item = Coord> {
+    let step = (b - a).signum();
+    let steps = a.chebyshev(b);
+    (0..=steps).map(move |i| a + step * i)
+}
+
+#[derive(Debug, Clone)]
+pub struct Cave {
+    pub cave: Sparse<Rock>,
+    pub source: i64,
+    pub floor: i64,
+}
+
+impl Cave {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let mut cave = Sparse::new();
+        for line in parse::lines(Day14::DAY, input) {
+            let vertices = Self::parse_path(line)?;
+            let stones = vertices
+                .windows(2)
+                .flat_map(|window| draw(window[0], window[1]))
+                .map(|p| (p, Rock::Stone));
+            cave.extend(stones);
+        }
+        let floor = match cave.bounds() {
+            Some(bounds) => bounds.max.y,
+            None => return Err(parse::end_of_input(Day14::DAY, input, "a rock path")),
+        };
+        Ok(Self {
+            cave,
+            source: 500,
+            floor,
+        })
+    }
+
+    /// Parses a line like `498,4 -> 498,6 -> 496,6`
+    fn parse_path(line: Line) -> Result<Vec<Coord>, ParseError> {
+        let expected = "a point like 498,4";
+        let mut vertices: Vec<Coord> = Vec::new();
+        for coords in line.text.split(" -> ") {
+            let (x, y) = coords
+                .split_once(',')
+                .ok_or_else(|| line.error(coords, expected))?;
+            let vertex = Coord::new(line.parse(x, expected)?, line.parse(y, expected)?);
+            if let Some(&previous) = vertices.last() {
+                if previous.x != vertex.x && previous.y != vertex.y {
+                    return Err(line.error(coords, "a point in line with the previous one"));
+                }
+            }
+            vertices.push(vertex);
+        }
+        Ok(vertices)
+    }
+
+    pub fn add_sand(&mut self) -> bool {
+        match self.simulate_sand() {
+            Some(sand) => {
+                self.cave.insert(sand, Rock::Sand);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn simulate_sand(&mut self) -> Option<Coord> {
+        let mut current = Coord::new(self.source, 0);
+        while let Some(new) = self.next_sand_position(current) {
+            if current == new {
+                return Some(current);
+            }
+            current = new;
+        }
+        None
+    }
+
+    fn next_sand_position(&self, at: Coord) -> Option<Coord> {
+        // check directly beneath
+        if self.cave.contains(at + DOWN) {
+            // check down and to the left
+            if !self.cave.contains(at + DOWN_LEFT) {
+                return Some(at + DOWN_LEFT);
+            }
+            if !self.cave.contains(at + DOWN_RIGHT) {
+                return Some(at + DOWN_RIGHT);
+            }
+            return Some(at);
+        }
+        if at.y + 1 >= self.floor {
+            None
+        } else {
+            Some(at + DOWN)
+        }
+    }
+
+    pub fn fill_cave(&mut self) -> Coord {
+        let next = self.simulate_sand_with_floor();
+        self.cave.insert(next, Rock::Sand);
+        next
+    }
+
+    fn simulate_sand_with_floor(&mut self) -> Coord {
+        let mut current = Coord::new(self.source, 0);
+        loop {
+            let new = self.next_sand_position_with_floor(current);
+            if current == new {
+                return current;
+            }
+            current = new;
+        }
+    }
+
+    fn next_sand_position_with_floor(&self, at: Coord) -> Coord {
+        if at.y + 1 == self.floor {
+            return at;
+        }
+        // check directly beneath
+        if self.cave.contains(at + DOWN) {
+            // check down and to the left
+            if !self.cave.contains(at + DOWN_LEFT) {
+                return at + DOWN_LEFT;
+            }
+            if !self.cave.contains(at + DOWN_RIGHT) {
+                return at + DOWN_RIGHT;
+            }
+            return at;
+        }
+        at + DOWN
+    }
+
+    pub fn sand(&self) -> usize {
+        self.cave
+            .iter()
+            .filter(|(_, rock)| matches!(rock, Rock::Sand))
+            .count()
+    }
+}
+
+impl Display for Cave {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let source = Coord::new(self.source, 0);
+        let bounds = match self.cave.bounds() {
+            Some(bounds) => bounds.include(source),
+            None => Bounds::new(source, source),
+        };
+        let drawing = self.cave.render_within(bounds, |at, rock| match rock {
+            Some(Rock::Stone) => '#',
+            Some(Rock::Sand) => 'o',
+            None if at == source => '+',
+            None => '.',
+        });
+        write!(f, "{drawing}")
+    }
+}
+
+pub fn part1(cave: &Cave) -> usize {
+    let mut cave = cave.clone();
+    while cave.add_sand() {}
+    cave.sand()
+}
+
+pub fn part2(cave: &Cave) -> usize {
+    let mut cave = cave.clone();
+    cave.floor += 2;
+    while cave.fill_cave() != Coord::new(cave.source, 0) {}
+    cave.sand()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Cave;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Cave::new(input)
+    }
+
+    fn part1(cave: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part1(cave))
+    }
+
+    fn part2(cave: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part2(cave))
+    }
+}
@@ -0,0 +1,118 @@
+use std::{collections::HashSet, error::Error, fmt::Display};
+
+use crate::{
+    error::ParseError,
+    interval::{Interval, IntervalSet},
+    parse::{self, Line},
+    point::Point2,
+    solution::{Solution, SolveError},
+};
+
+pub type Position = Point2<i64>;
+
+#[derive(Debug)]
+pub struct Sensor {
+    pub position: Position,
+    pub beacon: Position,
+}
+
+impl Sensor {
+    /// Parses a line holding the sensor's coordinates followed by its beacon's
+    pub fn parse(line: Line) -> Result<Self, ParseError> {
+        let mut tokens = line.tokens();
+        let mut coord = || tokens.parse::<i64>("a coordinate");
+        let position = Position::new(coord()?, coord()?);
+        let beacon = Position::new(coord()?, coord()?);
+        tokens.end()?;
+        Ok(Self { position, beacon })
+    }
+
+    pub fn radius(&self) -> i64 {
+        self.position.manhattan(self.beacon)
+    }
+
+    /// The part of the row at `depth` this sensor can see, if any
+    pub fn scan(&self, depth: i64) -> Option<Interval<i64>> {
+        let Position { x, y } = self.position;
+        let budget = self.radius() - (y - depth).abs();
+        Interval::new(x - budget, x + budget)
+    }
+}
+
+/// Every position in the row at `depth` that some sensor can see
+pub fn coverage(sensors: &[Sensor], depth: i64) -> IntervalSet<i64> {
+    sensors.iter().filter_map(|s| s.scan(depth)).collect()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    let sensors: Vec<_> = parse::lines(Day15::DAY, input)
+        .map(Sensor::parse)
+        .collect::<Result<_, _>>()?;
+    if sensors.is_empty() {
+        return Err(parse::end_of_input(Day15::DAY, input, "a sensor"));
+    }
+    Ok(sensors)
+}
+
+/// Number of positions in the row at `depth` which cannot contain a beacon
+pub fn part1(sensors: &[Sensor], depth: i64) -> usize {
+    let covered = coverage(sensors, depth);
+    let beacons: HashSet<_> = sensors.iter().map(|s| s.beacon).collect();
+    let beacons_in_row = beacons
+        .iter()
+        .filter(|beacon| beacon.y == depth && covered.contains(beacon.x))
+        .count();
+    let positions = covered
+        .total_len()
+        .expect("a row is narrower than the range of i64");
+    positions as usize - beacons_in_row
+}
+
+/// Every position within the search area is in range of some sensor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoGap {
+    pub upper: i64,
+}
+
+impl Display for NoGap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "no uncovered position within 0..={}", self.upper)
+    }
+}
+
+impl Error for NoGap {}
+
+/// Tuning frequency of the only position within `0..=upper` on both axes that
+/// no sensor can see
+pub fn part2(sensors: &[Sensor], upper: i64) -> Result<i64, NoGap> {
+    let bounds = Interval::new(0, upper).ok_or(NoGap { upper })?;
+    (0..=upper)
+        .find_map(|depth| {
+            let gaps = coverage(sensors, depth).gaps(bounds);
+            let x = gaps.iter().next()?.start;
+            Some(x * 4_000_000 + depth)
+        })
+        .ok_or(NoGap { upper })
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Vec<Sensor>;
+    type Part1 = usize;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(sensors: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part1(sensors, 2_000_000))
+    }
+
+    fn part2(sensors: &Self::Input) -> Result<i64, SolveError> {
+        Ok(part2(sensors, 4_000_000)?)
+    }
+}
@@ -0,0 +1,86 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    error::ParseError,
+    parse::{self, Line},
+    solution::{Solution, SolveError, Unsolved},
+};
+
+#[derive(Debug)]
+pub struct Valve {
+    pub flow_rate: i32,
+    pub leads_to: Vec<String>,
+}
+
+impl Valve {
+    /// Parses a line holding the valve's name, its flow rate and the valves
+    /// its tunnels lead to
+    pub fn new(line: Line) -> Result<(String, Self), ParseError> {
+        let mut tokens = line.tokens();
+        let name = tokens.next("a valve name")?.to_string();
+        let flow_rate = tokens.parse("a flow rate")?;
+        let leads_to = line
+            .text
+            .split_whitespace()
+            .skip(2)
+            .map(|s| s.to_string())
+            .collect();
+        Ok((
+            name,
+            Self {
+                flow_rate,
+                leads_to,
+            },
+        ))
+    }
+}
+
+#[derive(Debug)]
+pub struct Volcano {
+    pub volcano: HashMap<String, Valve>,
+}
+
+impl Volcano {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let volcano = parse::lines(Day16::DAY, input)
+            .map(Valve::new)
+            .collect::<Result<_, _>>()?;
+        Ok(Self { volcano })
+    }
+
+    pub fn score(&self, path: &[String]) -> i32 {
+        let mut score = 0;
+        let mut open = HashSet::new();
+        for valve in path {
+            open.insert(valve);
+            score += open
+                .iter()
+                .filter_map(|valve| self.volcano.get(*valve))
+                .map(|valve| valve.flow_rate)
+                .sum::<i32>()
+        }
+        score
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Volcano;
+    type Part1 = Unsolved;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Volcano::new(input)
+    }
+
+    fn part1(_: &Self::Input) -> Result<Unsolved, SolveError> {
+        Ok(Unsolved)
+    }
+
+    fn part2(_: &Self::Input) -> Result<Unsolved, SolveError> {
+        Ok(Unsolved)
+    }
+}
@@ -0,0 +1,233 @@
+use std::fmt::Display;
+
+use crate::{
+    error::ParseError,
+    grid::{Bounds, Coord, Grid, Sparse},
+    parse,
+    solution::{Solution, SolveError, Unsolved},
+};
+
+pub const ORDER: [Shape; 5] = [
+    Shape::Flat,
+    Shape::Plus,
+    Shape::Angle,
+    Shape::Wall,
+    Shape::Square,
+];
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    Left,
+    Right,
+    Down,
+}
+
+impl Direction {
+    pub fn parse(c: char) -> Option<Self> {
+        match c {
+            '<' => Some(Direction::Left),
+            '>' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    pub fn move_in(&self, at: &Coord) -> Coord {
+        *at + match self {
+            Direction::Left => Coord::new(-1, 0),
+            Direction::Right => Coord::new(1, 0),
+            Direction::Down => Coord::new(0, -1),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Shape {
+    Flat,
+    Plus,
+    Angle,
+    Wall,
+    Square,
+}
+
+impl Shape {
+    /// The points the shape covers with its bottom left corner at `at`
+    pub fn points(&self, at: Coord) -> Vec<Coord> {
+        use Shape::*;
+        let offsets: &[(i64, i64)] = match self {
+            Flat => &[(0, 0), (1, 0), (2, 0), (3, 0)],
+            Plus => &[(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)],
+            Angle => &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+            Wall => &[(0, 0), (0, 1), (0, 2), (0, 3)],
+            Square => &[(0, 0), (0, 1), (1, 0), (1, 1)],
+        };
+        offsets
+            .iter()
+            .map(|&offset| at + Coord::from(offset))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Rock {
+    pub shape: Shape,
+    pub position: Coord,
+}
+
+impl Rock {
+    pub fn points(&self) -> Vec<Coord> {
+        self.shape.points(self.position)
+    }
+}
+
+#[derive(Debug)]
+pub struct Cavern {
+    // unlike a character map, y counts upwards from the floor
+    occupied: Sparse<()>,
+    width: i64,
+    /// The highest occupied row, or -1 while the cavern is empty. Kept as
+    /// rocks land so that finding it doesn't mean scanning every cell.
+    top: i64,
+}
+
+impl Cavern {
+    pub fn new(width: i64) -> Self {
+        Cavern {
+            width,
+            occupied: Sparse::new(),
+            top: -1,
+        }
+    }
+
+    pub fn get(&self, at: Coord) -> bool {
+        if at.x < 0 || at.x >= self.width {
+            return true;
+        }
+        if at.y < 0 {
+            return true;
+        }
+        self.occupied.contains(at)
+    }
+
+    fn move_in(&self, rock: &Rock, direction: Direction) -> Coord {
+        let new_pos = direction.move_in(&rock.position);
+        let can_move = rock
+            .shape
+            .points(new_pos)
+            .iter()
+            .all(|position| !self.get(*position));
+        if can_move {
+            new_pos
+        } else {
+            rock.position
+        }
+    }
+
+    fn insert(&mut self, rock: Rock) {
+        for point in rock.points() {
+            self.top = self.top.max(point.y);
+            self.occupied.insert(point, ());
+        }
+    }
+
+    pub fn height(&self) -> i64 {
+        self.top
+    }
+
+    pub fn purge(&mut self) {
+        let height = self.height();
+        self.occupied.retain(|at, _| at.y >= height - 10000);
+    }
+
+    pub fn simulate(&mut self, shape: Shape, jets: &mut impl Iterator<Item = Direction>) {
+        let mut rock = Rock {
+            shape,
+            position: Coord::new(2, self.height() + 4),
+        };
+        for direction in jets {
+            // attempt to move laterally
+            rock.position = self.move_in(&rock, direction);
+            // only stop if we can't move downwards
+            let next = self.move_in(&rock, Direction::Down);
+            if next == rock.position {
+                self.insert(rock.clone());
+                return;
+            } else {
+                rock.position = next;
+            }
+        }
+    }
+}
+
+impl Display for Cavern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bounds = Bounds::new(Coord::ORIGIN, Coord::new(self.width - 1, self.height()));
+        let drawing = self
+            .occupied
+            .render_within(bounds, |_, rock| if rock.is_some() { '#' } else { '.' });
+        // the top of the tower goes at the top of the drawing
+        for row in drawing.lines().rev() {
+            writeln!(f, "{row}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Height of the tower after `rocks` rocks have come to rest
+pub fn tower_height(jets: &[Direction], rocks: usize) -> i64 {
+    let mut cavern = Cavern::new(7);
+    let mut jets = jets.iter().copied().cycle();
+    for (i, shape) in ORDER.into_iter().cycle().take(rocks).enumerate() {
+        cavern.simulate(shape, &mut jets);
+        if i % 10_000 == 0 && i > 0 {
+            cavern.purge()
+        }
+    }
+    cavern.height() + 1
+}
+
+/// The jet pattern, which may be wrapped over several lines
+pub fn parse(input: &str) -> Result<Vec<Direction>, ParseError> {
+    let mut jets = Vec::new();
+    for line in parse::lines(Day17::DAY, input) {
+        for (i, c) in line.text.char_indices() {
+            match Direction::parse(c) {
+                Some(direction) => jets.push(direction),
+                None => return Err(line.error(line.char_at(i), "a jet of gas (< or >)")),
+            }
+        }
+    }
+    if jets.is_empty() {
+        return Err(parse::end_of_input(
+            Day17::DAY,
+            input,
+            "a jet of gas (< or >)",
+        ));
+    }
+    Ok(jets)
+}
+
+pub fn part1(jets: &[Direction]) -> i64 {
+    tower_height(jets, 2022)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = Vec<Direction>;
+    type Part1 = i64;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(jets: &Self::Input) -> Result<i64, SolveError> {
+        Ok(part1(jets))
+    }
+
+    fn part2(_: &Self::Input) -> Result<Unsolved, SolveError> {
+        Ok(Unsolved)
+    }
+}
@@ -0,0 +1,169 @@
+use itertools::Itertools;
+use std::collections::{HashSet, VecDeque};
+
+use crate::{
+    error::ParseError,
+    parse::{self, Line},
+    point::Point3,
+    solution::{Solution, SolveError},
+};
+
+pub type Point = Point3<i32>;
+
+/// Parses a line like `2,2,2`
+pub fn parse_point(line: Line) -> Result<Point, ParseError> {
+    let mut coords = line.text.split(',');
+    let mut coord = || match coords.next() {
+        Some(token) => line.parse(token, "a coordinate"),
+        None => Err(line.error(line.end(), "a coordinate")),
+    };
+    let point = Point::new(coord()?, coord()?, coord()?);
+    match coords.next() {
+        Some(extra) => Err(line.error(extra, "end of line")),
+        None => Ok(point),
+    }
+}
+
+pub fn parse(input: &str) -> Result<HashSet<Point>, ParseError> {
+    let shape: HashSet<_> = parse::lines(Day18::DAY, input)
+        .map(parse_point)
+        .collect::<Result<_, _>>()?;
+    if shape.is_empty() {
+        return Err(parse::end_of_input(Day18::DAY, input, "a cube"));
+    }
+    Ok(shape)
+}
+
+fn exposed(point: &Point, shape: &HashSet<Point>) -> usize {
+    Point::NEIGHBORS_6
+        .iter()
+        .map(|&direction| *point + direction)
+        .filter(|p| !shape.contains(p))
+        .count()
+}
+
+pub fn surface_area(shape: &HashSet<Point>) -> usize {
+    shape.iter().map(|point| exposed(point, shape)).sum()
+}
+
+#[derive(Debug)]
+pub struct Bounds {
+    pub low: Point,
+    pub high: Point,
+}
+
+impl Bounds {
+    pub fn new(shape: &HashSet<Point>) -> Self {
+        // find the two corners that define the bounding box of this shape
+        let mut points = shape.iter();
+        let mut low = *points.next().unwrap();
+        let mut high = low;
+        for &p in points {
+            low = low.min_each(p);
+            high = high.max_each(p);
+        }
+
+        let margin = Point::new(1, 1, 1);
+        Self {
+            low: low - margin,
+            high: high + margin,
+        }
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        // the point is inside if clamping it to the box leaves it where it is
+        point.max_each(self.low).min_each(self.high) == *point
+    }
+
+    pub fn exterior_surface_area(&self) -> i32 {
+        let Point {
+            x: delta_x,
+            y: delta_y,
+            z: delta_z,
+        } = self.high - self.low + Point::new(1, 1, 1);
+
+        (2 * delta_x * delta_y) + (2 * delta_x * delta_z) + (2 * delta_y * delta_z)
+    }
+}
+
+pub fn bfs_complement(shape: &HashSet<Point>, bounds: &Bounds) -> HashSet<Point> {
+    // this will both let us know which points we've visited and let us compute
+    // the final surface area when we're done
+    let mut complement = HashSet::new();
+
+    // queue for breadth-first search.
+    let mut queue = VecDeque::new();
+    queue.push_front(bounds.high);
+
+    // we have to mark the starting point as explored before entering the loop
+    complement.insert(bounds.high);
+
+    // breadth-first search
+    while let Some(point) = queue.pop_front() {
+        // these are all the points to visit next. they must be inside our
+        // bounding box and neither part of the original shape nor the
+        // complement
+        let candidates = Point::NEIGHBORS_6
+            .iter()
+            .map(|&direction| point + direction)
+            .filter(|neighbor| bounds.contains(neighbor))
+            .filter(|neighbor| !shape.contains(neighbor))
+            .filter(|neighbor| !complement.contains(neighbor))
+            .collect_vec();
+
+        // once we've generated all of the new points, we can mark them, as
+        // visited and then enqueue them to be explored later
+        for point in candidates {
+            complement.insert(point);
+            queue.push_back(point)
+        }
+    }
+
+    complement
+}
+
+pub fn part1(shape: &HashSet<Point>) -> usize {
+    surface_area(shape)
+}
+
+pub fn part2(shape: &HashSet<Point>) -> usize {
+    // create a cuboid which is slightly larger than the original shape
+    let bounds = Bounds::new(shape);
+
+    // We're going to start "filling" this bounded cuboid of space with a
+    // complementary set of points, starting at a position we know for certain
+    // is outside of the shape. When we've completely filled this exterior
+    // volume, we'll know that any remaining spaces which are in bound but
+    // members of neither the original shape nor the complementary set must be
+    // air pockets.
+    let complement = bfs_complement(shape, &bounds);
+
+    // We can use the surface area of the complementary set to get the exterior
+    // surface area of our original shape, but we first need to subtract the
+    // exterior surface area of the *complement*. Thankfully, since it's
+    // guaranteed to be some kind of cuboid we actually have an explicit
+    // equation for it.
+    surface_area(&complement) - bounds.exterior_surface_area() as usize
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = HashSet<Point>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(shape: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part1(shape))
+    }
+
+    fn part2(shape: &Self::Input) -> Result<usize, SolveError> {
+        Ok(part2(shape))
+    }
+}
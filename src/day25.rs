@@ -0,0 +1,102 @@
+use crate::{
+    error::ParseError,
+    parse::{self, Line},
+    solution::{Solution, SolveError, Unsolved},
+};
+
+const DIGIT: &str = "a SNAFU digit (=, -, 0, 1 or 2)";
+const IN_RANGE: &str = "a number that fits in 64 bits";
+
+fn digit(c: char) -> Option<i64> {
+    match c {
+        '=' => Some(-2),
+        '-' => Some(-1),
+        '0' => Some(0),
+        '1' => Some(1),
+        '2' => Some(2),
+        _ => None,
+    }
+}
+
+/// Adds one more digit to the right of `value`, or `None` if the result
+/// doesn't fit in an `i64`
+fn push_digit(value: i64, digit: i64) -> Option<i64> {
+    value.checked_mul(5)?.checked_add(digit)
+}
+
+/// `None` if there's a character that isn't a SNAFU digit, or if the number is
+/// too big for an `i64`
+pub fn from_snafu(input: &str) -> Option<i64> {
+    input
+        .chars()
+        .try_fold(0, |value, c| push_digit(value, digit(c)?))
+}
+
+pub fn to_snafu(mut value: i64) -> String {
+    let mut digits = Vec::new();
+    while value > 0 {
+        // remainder mod 5
+        let remainder = value % 5;
+        let adjusted = match remainder {
+            4 => -1,
+            3 => -2,
+            x => x,
+        };
+        digits.push(adjusted);
+        value -= adjusted;
+        value /= 5;
+    }
+    digits
+        .iter()
+        .rev()
+        .map(|d| match d {
+            -2 => '=',
+            -1 => '-',
+            0 => '0',
+            1 => '1',
+            2 => '2',
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// Reads a line as a SNAFU number, pointing at the digit where it goes wrong:
+/// the first that isn't one, or the first that takes it out of range
+pub fn parse_number(line: Line) -> Result<i64, ParseError> {
+    let mut value = 0;
+    for (i, c) in line.text.char_indices() {
+        let digit = digit(c).ok_or_else(|| line.error(line.char_at(i), DIGIT))?;
+        value = push_digit(value, digit).ok_or_else(|| line.error(line.char_at(i), IN_RANGE))?;
+    }
+    Ok(value)
+}
+
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    parse::lines(Day25::DAY, input).map(parse_number).collect()
+}
+
+pub fn part1(numbers: &[i64]) -> String {
+    to_snafu(numbers.iter().sum())
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input = Vec<i64>;
+    type Part1 = String;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(numbers: &Self::Input) -> Result<String, SolveError> {
+        Ok(part1(numbers))
+    }
+
+    fn part2(_: &Self::Input) -> Result<Unsolved, SolveError> {
+        Ok(Unsolved)
+    }
+}
@@ -0,0 +1,203 @@
+use std::{error::Error, fmt::Display, io, path::PathBuf};
+
+use crate::solution::Part;
+
+/// Where and why a puzzle input couldn't be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column, counted in characters
+    pub column: usize,
+    /// The offending token, which is empty if the line ended too early
+    pub token: String,
+    /// A description of what the grammar allows at this point
+    pub expected: String,
+    /// The full text of the offending line
+    pub text: String,
+}
+
+impl ParseError {
+    /// A compiler-style rendering of the error, with the offending line and a
+    /// marker underneath the token
+    pub fn diagnostic(&self) -> String {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let indent = " ".repeat(self.column - 1);
+        let marker = "^".repeat(self.token.chars().count().max(1));
+        format!(
+            "expected {}, found {}\n{gutter}--> day {}, line {}, column {}\n{gutter} |\n{number} | {}\n{gutter} | {indent}{marker}",
+            self.expected,
+            self.found(),
+            self.day,
+            self.line,
+            self.column,
+            self.text,
+        )
+    }
+
+    fn found(&self) -> String {
+        match self.token.as_str() {
+            "" => "end of line".into(),
+            token => format!("{token:?}"),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: expected {}, found {}",
+            self.day,
+            self.line,
+            self.column,
+            self.expected,
+            self.found()
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing(PathBuf),
+    Io(String, io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing(path) => write!(f, "no input at {}", path.display()),
+            InputError::Io(source, e) => write!(f, "couldn't read {source}: {e}"),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Missing(_) => None,
+            InputError::Io(_, e) => Some(e),
+        }
+    }
+}
+
+/// Why an answers manifest couldn't be read
+#[derive(Debug)]
+pub enum ManifestError {
+    Input(InputError),
+    Syntax {
+        /// 1-based line number
+        line: usize,
+        expected: String,
+        text: String,
+    },
+}
+
+impl Display for ManifestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ManifestError::Input(e) => write!(f, "{e}"),
+            ManifestError::Syntax {
+                line,
+                expected,
+                text,
+            } => write!(f, "manifest line {line}: expected {expected} in {text:?}"),
+        }
+    }
+}
+
+impl Error for ManifestError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ManifestError::Input(e) => Some(e),
+            ManifestError::Syntax { .. } => None,
+        }
+    }
+}
+
+impl From<InputError> for ManifestError {
+    fn from(e: InputError) -> Self {
+        ManifestError::Input(e)
+    }
+}
+
+/// Why input read incrementally from a stream couldn't be used
+#[derive(Debug)]
+pub enum ReadError {
+    Input(InputError),
+    Parse(ParseError),
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadError::Input(e) => write!(f, "{e}"),
+            ReadError::Parse(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for ReadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReadError::Input(e) => Some(e),
+            ReadError::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(e: ParseError) -> Self {
+        ReadError::Parse(e)
+    }
+}
+
+/// Why a day couldn't answer from its input
+#[derive(Debug)]
+pub enum RunError {
+    Parse(ParseError),
+    /// The input parsed, but a part has no answer for it
+    Solve {
+        day: u8,
+        part: Part,
+        error: Box<dyn Error>,
+    },
+}
+
+impl RunError {
+    /// Like [`ParseError::diagnostic`], for parse errors
+    pub fn diagnostic(&self) -> String {
+        match self {
+            RunError::Parse(e) => e.diagnostic(),
+            RunError::Solve { .. } => self.to_string(),
+        }
+    }
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunError::Parse(e) => write!(f, "{e}"),
+            RunError::Solve { day, part, error } => write!(f, "day {day}, part {part}: {error}"),
+        }
+    }
+}
+
+impl Error for RunError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RunError::Parse(e) => Some(e),
+            RunError::Solve { error, .. } => Some(error.as_ref()),
+        }
+    }
+}
+
+impl From<ParseError> for RunError {
+    fn from(e: ParseError) -> Self {
+        RunError::Parse(e)
+    }
+}
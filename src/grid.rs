@@ -0,0 +1,302 @@
+use std::collections::HashMap;
+
+use crate::{error::ParseError, parse, point::Point2};
+
+/// A position on a grid. `x` counts columns to the right and `y` counts rows
+/// downwards, so a character map's first line is `y = 0`.
+pub type Coord = Point2<i64>;
+
+/// An inclusive rectangle of coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Coord,
+    pub max: Coord,
+}
+
+impl Bounds {
+    pub fn new(min: Coord, max: Coord) -> Self {
+        Bounds { min, max }
+    }
+
+    /// The smallest bounds holding every coordinate, if there are any
+    pub fn around(coords: impl IntoIterator<Item = Coord>) -> Option<Self> {
+        coords.into_iter().fold(None, |bounds, c| match bounds {
+            None => Some(Bounds::new(c, c)),
+            Some(b) => Some(b.include(c)),
+        })
+    }
+
+    /// These bounds, grown to take in `c`
+    pub fn include(self, c: Coord) -> Self {
+        Bounds {
+            min: self.min.min_each(c),
+            max: self.max.max_each(c),
+        }
+    }
+
+    pub fn contains(&self, c: Coord) -> bool {
+        (self.min.x..=self.max.x).contains(&c.x) && (self.min.y..=self.max.y).contains(&c.y)
+    }
+
+    pub fn width(&self) -> i64 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i64 {
+        self.max.y - self.min.y + 1
+    }
+
+    /// Every coordinate inside, row by row
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let Bounds { min, max } = *self;
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Coord::new(x, y)))
+    }
+}
+
+/// Cells addressed by [`Coord`], whichever way they're stored
+pub trait Grid<T> {
+    fn get(&self, at: Coord) -> Option<&T>;
+
+    /// The smallest rectangle holding every cell, or `None` if there are none
+    fn bounds(&self) -> Option<Bounds>;
+
+    fn contains(&self, at: Coord) -> bool {
+        self.get(at).is_some()
+    }
+
+    /// The orthogonal neighbours of `at` which hold a value, in the order of
+    /// [`Point2::NEIGHBORS_4`]
+    fn neighbors4<'a>(&'a self, at: Coord) -> impl Iterator<Item = (Coord, &'a T)>
+    where
+        T: 'a,
+    {
+        Coord::NEIGHBORS_4
+            .iter()
+            .filter_map(move |&delta| self.cell(at + delta))
+    }
+
+    /// All neighbours of `at`, diagonals included, which hold a value
+    fn neighbors8<'a>(&'a self, at: Coord) -> impl Iterator<Item = (Coord, &'a T)>
+    where
+        T: 'a,
+    {
+        Coord::NEIGHBORS_8
+            .iter()
+            .filter_map(move |&delta| self.cell(at + delta))
+    }
+
+    /// The cells met walking from `from` (exclusive) in steps of `delta`, up
+    /// to the first position with no value
+    fn ray<'a>(&'a self, from: Coord, delta: Coord) -> impl Iterator<Item = (Coord, &'a T)>
+    where
+        T: 'a,
+    {
+        let mut at = from;
+        std::iter::from_fn(move || {
+            at += delta;
+            self.cell(at)
+        })
+    }
+
+    /// Draws every cell inside `bounds`, one line per row
+    fn render_within(&self, bounds: Bounds, cell: impl Fn(Coord, Option<&T>) -> char) -> String {
+        (bounds.min.y..=bounds.max.y)
+            .map(|y| {
+                (bounds.min.x..=bounds.max.x)
+                    .map(|x| Coord::new(x, y))
+                    .map(|at| cell(at, self.get(at)))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Draws the grid, one line per row
+    fn render(&self, cell: impl Fn(Coord, Option<&T>) -> char) -> String {
+        match self.bounds() {
+            Some(bounds) => self.render_within(bounds, cell),
+            None => String::new(),
+        }
+    }
+
+    fn cell(&self, at: Coord) -> Option<(Coord, &T)> {
+        self.get(at).map(|value| (at, value))
+    }
+}
+
+/// A rectangle with a value in every cell, starting at `(0, 0)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dense<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Dense<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Dense {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Reads a character map, one row per line. `cell` turns each character
+    /// into a value, or says what it expected instead.
+    pub fn parse<E: Into<String>>(
+        day: u8,
+        input: &str,
+        mut cell: impl FnMut(Coord, char) -> Result<T, E>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in parse::lines(day, input).enumerate() {
+            let mut row = 0;
+            for (x, (i, c)) in line.text.char_indices().enumerate() {
+                if width.is_some_and(|width| x >= width) {
+                    let expected = format!("a row of {} cells", width.unwrap());
+                    return Err(line.error(&line.text[i..], expected));
+                }
+                let value = cell(Coord::new(x as i64, y as i64), c)
+                    .map_err(|e| line.error(line.char_at(i), e))?;
+                cells.push(value);
+                row += 1;
+            }
+            match width {
+                Some(width) if row < width => {
+                    return Err(line.error(line.end(), format!("a row of {width} cells")));
+                }
+                _ => width = Some(row),
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Dense {
+                cells,
+                width,
+                height,
+            }),
+            _ => Err(parse::end_of_input(day, input, "a row of cells")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, at: Coord) -> Option<usize> {
+        let (x, y) = (usize::try_from(at.x).ok()?, usize::try_from(at.y).ok()?);
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn get_mut(&mut self, at: Coord) -> Option<&mut T> {
+        self.index(at).map(|i| &mut self.cells[i])
+    }
+
+    /// Every cell with its coordinate, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, value)| (Coord::new((i % width) as i64, (i / width) as i64), value))
+    }
+
+    /// A grid of the same shape holding `f` of every cell
+    pub fn map<U>(&self, mut f: impl FnMut(Coord, &T) -> U) -> Dense<U> {
+        Dense {
+            cells: self.iter().map(|(at, value)| f(at, value)).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Grid<T> for Dense<T> {
+    fn get(&self, at: Coord) -> Option<&T> {
+        self.index(at).map(|i| &self.cells[i])
+    }
+
+    fn bounds(&self) -> Option<Bounds> {
+        let max = Coord::new(self.width as i64 - 1, self.height as i64 - 1);
+        (!self.cells.is_empty()).then_some(Bounds::new(Coord::ORIGIN, max))
+    }
+}
+
+/// Values scattered over an unbounded plane, where most positions are empty
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sparse<T> {
+    cells: HashMap<Coord, T>,
+}
+
+impl<T> Default for Sparse<T> {
+    fn default() -> Self {
+        Sparse {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> Sparse<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, at: Coord, value: T) -> Option<T> {
+        self.cells.insert(at, value)
+    }
+
+    pub fn remove(&mut self, at: Coord) -> Option<T> {
+        self.cells.remove(&at)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Every cell with its coordinate, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.cells.iter().map(|(&at, value)| (at, value))
+    }
+
+    /// Drops every cell for which `keep` is false
+    pub fn retain(&mut self, mut keep: impl FnMut(Coord, &T) -> bool) {
+        self.cells.retain(|&at, value| keep(at, value));
+    }
+}
+
+impl<T> Grid<T> for Sparse<T> {
+    fn get(&self, at: Coord) -> Option<&T> {
+        self.cells.get(&at)
+    }
+
+    fn bounds(&self) -> Option<Bounds> {
+        Bounds::around(self.cells.keys().copied())
+    }
+}
+
+impl<T> FromIterator<(Coord, T)> for Sparse<T> {
+    fn from_iter<I: IntoIterator<Item = (Coord, T)>>(iter: I) -> Self {
+        Sparse {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Extend<(Coord, T)> for Sparse<T> {
+    fn extend<I: IntoIterator<Item = (Coord, T)>>(&mut self, iter: I) {
+        self.cells.extend(iter)
+    }
+}
@@ -0,0 +1,112 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::error::InputError;
+
+/// Where inputs live unless `AOC_INPUT_DIR` says otherwise
+pub const DEFAULT_DIR: &str = "tests/res";
+pub const DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The name of a day's real puzzle input
+pub const REAL: &str = "";
+/// The name of a day's example input. Days with several examples number them
+/// (`ex1`, `ex2`, ...), and this falls back to the first of those.
+pub const EXAMPLE: &str = "ex";
+
+/// Where to read a day's input from.
+///
+/// Inputs in a directory are named after the day and an optional suffix, so
+/// `09.txt` is the real input for day 9 and `09ex2.txt` is its second example.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Dir(PathBuf),
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// The directory named by `AOC_INPUT_DIR`, or [`DEFAULT_DIR`] if it's unset
+    pub fn from_env() -> Self {
+        match env::var_os(DIR_VAR) {
+            Some(dir) => Source::Dir(dir.into()),
+            None => Source::Dir(DEFAULT_DIR.into()),
+        }
+    }
+
+    /// A path given on the command line, where `-` means stdin
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::File(path.into()),
+        }
+    }
+
+    /// Resolves the file holding the named input for a day, if there is one.
+    /// Explicit files and stdin ignore the day and name.
+    pub fn resolve(&self, day: u8, name: &str) -> Option<PathBuf> {
+        match self {
+            Source::Dir(dir) => {
+                let path = dir.join(file_name(day, name));
+                if name == EXAMPLE && !path.exists() {
+                    return Some(dir.join(file_name(day, "ex1")));
+                }
+                Some(path)
+            }
+            Source::File(path) => Some(path.clone()),
+            Source::Stdin => None,
+        }
+    }
+
+    pub fn load(&self, day: u8, name: &str) -> Result<String, InputError> {
+        match self.resolve(day, name) {
+            Some(path) => read(&path),
+            None => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| InputError::Io("stdin".into(), e))?;
+                Ok(normalize(&input))
+            }
+        }
+    }
+}
+
+pub fn file_name(day: u8, name: &str) -> String {
+    format!("{day:02}{name}.txt")
+}
+
+/// A day's real input from the default source
+pub fn real(day: u8) -> Result<String, InputError> {
+    Source::from_env().load(day, REAL)
+}
+
+/// One of a day's named inputs (e.g. `ex` or `ex2`) from the default source
+pub fn named(day: u8, name: &str) -> Result<String, InputError> {
+    Source::from_env().load(day, name)
+}
+
+/// Any file, whatever it's called
+pub fn file(path: &Path) -> Result<String, InputError> {
+    read(path)
+}
+
+fn read(path: &Path) -> Result<String, InputError> {
+    if !path.exists() {
+        return Err(InputError::Missing(path.into()));
+    }
+    fs::read_to_string(path)
+        .map(|input| normalize(&input))
+        .map_err(|e| InputError::Io(path.display().to_string(), e))
+}
+
+/// None of the parsers expect carriage returns or a trailing newline, which
+/// most editors leave behind
+fn normalize(input: &str) -> String {
+    input
+        .replace("\r\n", "\n")
+        .trim_end_matches('\n')
+        .to_string()
+}
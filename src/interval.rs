@@ -0,0 +1,279 @@
+use std::{
+    fmt::Display,
+    ops::{Add, RangeInclusive, Sub},
+};
+
+/// Integers that can bound an interval
+pub trait Endpoint: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! endpoint {
+    ($($t:ty),*) => {
+        $(impl Endpoint for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+        })*
+    };
+}
+
+endpoint!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// A non-empty run of integers from `start` to `end`, both included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Endpoint> Interval<T> {
+    /// `None` if the interval would be empty, i.e. `start > end`
+    pub fn new(start: T, end: T) -> Option<Self> {
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    pub fn point(at: T) -> Self {
+        Interval { start: at, end: at }
+    }
+
+    /// How many integers the interval holds, or `None` if that's too many for
+    /// `T`, as for `0..=u32::MAX`
+    pub fn len(&self) -> Option<T> {
+        self.end.checked_sub(self.start)?.checked_add(T::ONE)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// Whether every value of `other` is also in `self`
+    pub fn covers(&self, other: &Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// Whether the two intervals overlap or sit right next to each other, so
+    /// that together they form one interval
+    pub fn touches(&self, other: &Self) -> bool {
+        // the gap between far-apart signed endpoints can be wider than `T`
+        // holds, so step from one end rather than measuring it
+        self.overlaps(other)
+            || self.end.checked_add(T::ONE) == Some(other.start)
+            || other.end.checked_add(T::ONE) == Some(self.start)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The smallest interval covering both
+    pub fn hull(&self, other: &Self) -> Self {
+        Interval {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
+}
+
+impl<T: Endpoint> From<Interval<T>> for RangeInclusive<T> {
+    fn from(interval: Interval<T>) -> Self {
+        interval.start..=interval.end
+    }
+}
+
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// A set of integers stored as the fewest intervals that cover it: sorted,
+/// and with no two intervals overlapping or touching
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Endpoint> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { intervals: vec![] }
+    }
+
+    /// Adds every value in `interval`, merging it with any intervals it
+    /// overlaps or touches
+    pub fn insert(&mut self, interval: Interval<T>) {
+        // the first interval that could merge with the new one
+        let first = self
+            .intervals
+            .partition_point(|i| i.end < interval.start && !i.touches(&interval));
+        let mut last = first;
+        let mut merged = interval;
+        while let Some(next) = self.intervals.get(last) {
+            if !next.touches(&merged) {
+                break;
+            }
+            merged = merged.hull(next);
+            last += 1;
+        }
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// The intervals, lowest first
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    /// How many separate intervals the set is made of
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many values the set holds, or `None` if that's too many for `T`
+    pub fn total_len(&self) -> Option<T> {
+        self.intervals.iter().try_fold(T::ZERO, |total, interval| {
+            total.checked_add(interval.len()?)
+        })
+    }
+
+    /// The interval holding `value`, if any
+    pub fn find(&self, value: T) -> Option<&Interval<T>> {
+        let i = self.intervals.partition_point(|i| i.end < value);
+        self.intervals.get(i).filter(|i| i.contains(value))
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.find(value).is_some()
+    }
+
+    /// Whether every value of `interval` is in the set
+    pub fn covers(&self, interval: &Interval<T>) -> bool {
+        self.find(interval.start)
+            .is_some_and(|i| i.covers(interval))
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.iter().chain(other.iter()).copied().collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.iter().peekable(), other.iter().peekable());
+        let mut intervals = vec![];
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            intervals.extend(x.intersection(y));
+            // whichever ends first can't overlap anything else
+            if x.end < y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// The values in `self` but not in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let mut others = other.iter().peekable();
+        for &interval in self.iter() {
+            let mut rest = Some(interval);
+            while let (Some(r), Some(o)) = (rest, others.peek()) {
+                if o.end < r.start {
+                    others.next();
+                    continue;
+                }
+                if o.start > r.end {
+                    break;
+                }
+                // o.start is above r.start and o.end below r.end, so stepping
+                // past either can't overflow
+                if o.start > r.start {
+                    intervals.push(Interval {
+                        start: r.start,
+                        end: o.start - T::ONE,
+                    });
+                }
+                if o.end >= r.end {
+                    rest = None;
+                } else {
+                    rest = Some(Interval {
+                        start: o.end + T::ONE,
+                        end: r.end,
+                    });
+                    others.next();
+                }
+            }
+            intervals.extend(rest);
+        }
+        IntervalSet { intervals }
+    }
+
+    /// The values within `bounds` that aren't in the set, as intervals
+    pub fn gaps(&self, bounds: Interval<T>) -> Self {
+        IntervalSet::from(bounds).difference(self)
+    }
+}
+
+impl<T: Endpoint> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Endpoint> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        IntervalSet {
+            intervals: vec![interval],
+        }
+    }
+}
+
+/// Sorts the intervals once and merges them in a single pass, which is
+/// quicker than inserting them one by one
+impl<T: Endpoint> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut sorted: Vec<_> = iter.into_iter().collect();
+        sorted.sort_unstable_by_key(|i| i.start);
+        let mut intervals: Vec<Interval<T>> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match intervals.last_mut() {
+                Some(last) if last.touches(&interval) => *last = last.hull(&interval),
+                _ => intervals.push(interval),
+            }
+        }
+        IntervalSet { intervals }
+    }
+}
+
+impl<T: Endpoint> Extend<Interval<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Interval<T>>>(&mut self, iter: I) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+impl<T: Display> Display for IntervalSet<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let intervals: Vec<_> = self.intervals.iter().map(|i| i.to_string()).collect();
+        write!(f, "{{{}}}", intervals.join(", "))
+    }
+}
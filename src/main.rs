@@ -1,3 +1,571 @@
-fn main() {
-    println!("Hello, world!");
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+
+use aoc_22::{
+    bench::{self, Report},
+    day06::{self, MarkerKind},
+    error::InputError,
+    input::{self, Source},
+    manifest::{self, Manifest, Status},
+    registry,
+    solution::{Day, Part, Run},
+};
+
+const USAGE: &str = "\
+usage: aoc-22 list
+       aoc-22 run <day> [--part 1|2] [--input FILE|-] [--dir DIR] [--example | --name NAME]
+       aoc-22 run --all [--dir DIR] [--example | --name NAME]
+       aoc-22 verify [--dir DIR] [--manifest FILE]
+       aoc-22 bench <day>|--all [--runs N] [--dir DIR] [--example | --name NAME]
+                    [--save FILE] [--baseline FILE] [--threshold PERCENT]
+       aoc-22 frames <FILE|-> [--packet | --message | --size N]
+
+Inputs are read from DIR (default: $AOC_INPUT_DIR, then tests/res) as NN.txt,
+or NNex.txt for --example. --name picks any other suffix, e.g. --name ex2.
+verify checks the answers listed in FILE (default: DIR/answers.txt).
+bench times each stage over N runs (default: 10), saves the results as JSON
+and flags stages more than PERCENT (default: 10) slower than a saved baseline.
+frames splits a day 6 datastream at each start-of-message marker (or
+start-of-packet, or any N distinct characters) and prints the frames.";
+
+#[derive(Debug)]
+enum Command {
+    List,
+    Run {
+        day: u8,
+        part: Option<Part>,
+        source: Source,
+        name: String,
+    },
+    RunAll {
+        source: Source,
+        name: String,
+    },
+    Verify {
+        dir: PathBuf,
+        manifest: PathBuf,
+    },
+    Bench {
+        day: Option<u8>,
+        runs: usize,
+        source: Source,
+        name: String,
+        save: Option<PathBuf>,
+        baseline: Option<PathBuf>,
+        threshold: f64,
+    },
+    Frames {
+        source: Source,
+        kind: MarkerKind,
+    },
+}
+
+impl Command {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let (command, mut args) = match args.split_first() {
+            Some((command, rest)) => (command.as_str(), rest.iter()),
+            None => return Err("missing command".into()),
+        };
+        match command {
+            "list" => match args.next() {
+                None => Ok(Command::List),
+                Some(arg) => Err(format!("unexpected argument '{arg}'")),
+            },
+            "run" => {
+                let mut day = None;
+                let mut part = None;
+                let mut file = None;
+                let mut dir = None;
+                let mut name = None;
+                let mut all = false;
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--all" => all = true,
+                        "--part" => {
+                            part = match args.next().map(String::as_str) {
+                                Some("1") => Some(Part::One),
+                                Some("2") => Some(Part::Two),
+                                _ => return Err("--part expects 1 or 2".into()),
+                            }
+                        }
+                        "--input" => match args.next() {
+                            Some(path) => file = Some(Source::from_arg(path)),
+                            None => return Err("--input expects a file or '-'".into()),
+                        },
+                        "--dir" => match args.next() {
+                            Some(path) => dir = Some(Source::Dir(path.into())),
+                            None => return Err("--dir expects a directory".into()),
+                        },
+                        "--example" => name = Some(input::EXAMPLE.to_string()),
+                        "--name" => match args.next() {
+                            Some(suffix) => name = Some(suffix.clone()),
+                            None => return Err("--name expects an input name".into()),
+                        },
+                        _ => match arg.parse() {
+                            Ok(n) if day.is_none() => day = Some(n),
+                            _ => return Err(format!("unexpected argument '{arg}'")),
+                        },
+                    }
+                }
+                if file.is_some() && (dir.is_some() || name.is_some()) {
+                    return Err("--input can't be combined with --dir, --example or --name".into());
+                }
+                let source = file.or(dir).unwrap_or_else(Source::from_env);
+                let name = name.unwrap_or_else(|| input::REAL.to_string());
+                match (day, all) {
+                    (Some(day), false) => Ok(Command::Run {
+                        day,
+                        part,
+                        source,
+                        name,
+                    }),
+                    (None, true) if part.is_some() => {
+                        Err("--all can't be combined with --part".into())
+                    }
+                    (None, true) => match source {
+                        Source::Dir(_) => Ok(Command::RunAll { source, name }),
+                        _ => Err("--all can't be combined with --input".into()),
+                    },
+                    (Some(_), true) => Err("expected either a day or --all, not both".into()),
+                    (None, false) => Err("missing day".into()),
+                }
+            }
+            "verify" => {
+                let mut dir = None;
+                let mut manifest = None;
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--dir" => match args.next() {
+                            Some(path) => dir = Some(PathBuf::from(path)),
+                            None => return Err("--dir expects a directory".into()),
+                        },
+                        "--manifest" => match args.next() {
+                            Some(path) => manifest = Some(PathBuf::from(path)),
+                            None => return Err("--manifest expects a file".into()),
+                        },
+                        _ => return Err(format!("unexpected argument '{arg}'")),
+                    }
+                }
+                let dir = dir.unwrap_or_else(|| match Source::from_env() {
+                    Source::Dir(dir) => dir,
+                    _ => input::DEFAULT_DIR.into(),
+                });
+                let manifest = manifest.unwrap_or_else(|| dir.join(manifest::FILE_NAME));
+                Ok(Command::Verify { dir, manifest })
+            }
+            "bench" => {
+                let mut day = None;
+                let mut all = false;
+                let mut runs = 10;
+                let mut source = Source::from_env();
+                let mut name = input::REAL.to_string();
+                let mut save = None;
+                let mut baseline = None;
+                let mut threshold = 10.0;
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--all" => all = true,
+                        "--runs" => match args.next().map(|n| n.parse()) {
+                            Some(Ok(n)) if n > 0 => runs = n,
+                            _ => return Err("--runs expects a positive number".into()),
+                        },
+                        "--dir" => match args.next() {
+                            Some(path) => source = Source::Dir(path.into()),
+                            None => return Err("--dir expects a directory".into()),
+                        },
+                        "--example" => name = input::EXAMPLE.to_string(),
+                        "--name" => match args.next() {
+                            Some(suffix) => name = suffix.clone(),
+                            None => return Err("--name expects an input name".into()),
+                        },
+                        "--save" => match args.next() {
+                            Some(path) => save = Some(PathBuf::from(path)),
+                            None => return Err("--save expects a file".into()),
+                        },
+                        "--baseline" => match args.next() {
+                            Some(path) => baseline = Some(PathBuf::from(path)),
+                            None => return Err("--baseline expects a file".into()),
+                        },
+                        "--threshold" => match args.next().map(|n| n.parse()) {
+                            Some(Ok(n)) if n >= 0.0 => threshold = n,
+                            _ => return Err("--threshold expects a percentage".into()),
+                        },
+                        _ => match arg.parse() {
+                            Ok(n) if day.is_none() => day = Some(n),
+                            _ => return Err(format!("unexpected argument '{arg}'")),
+                        },
+                    }
+                }
+                match (day, all) {
+                    (Some(_), true) => Err("expected either a day or --all, not both".into()),
+                    (None, false) => Err("missing day".into()),
+                    _ => Ok(Command::Bench {
+                        day,
+                        runs,
+                        source,
+                        name,
+                        save,
+                        baseline,
+                        threshold: threshold / 100.0,
+                    }),
+                }
+            }
+            "frames" => {
+                let mut source = None;
+                let mut kind = MarkerKind::Message;
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--packet" => kind = MarkerKind::Packet,
+                        "--message" => kind = MarkerKind::Message,
+                        "--size" => match args.next().map(|n| n.parse()) {
+                            Some(Ok(n)) if n > 0 => kind = MarkerKind::Custom(n),
+                            _ => return Err("--size expects a positive number".into()),
+                        },
+                        path if source.is_none() => source = Some(Source::from_arg(path)),
+                        _ => return Err(format!("unexpected argument '{arg}'")),
+                    }
+                }
+                match source {
+                    Some(source) => Ok(Command::Frames { source, kind }),
+                    None => Err("missing signal file".into()),
+                }
+            }
+            _ => Err(format!("unknown command '{command}'")),
+        }
+    }
+}
+
+fn find_day(number: u8) -> Result<&'static Day, String> {
+    registry::find(number).ok_or_else(|| format!("day {number} hasn't been solved"))
+}
+
+fn list() {
+    for day in registry::DAYS {
+        println!("{:>2}  {}", day.number, day.title);
+    }
+}
+
+fn run(number: u8, part: Option<Part>, source: &Source, name: &str) -> Result<(), String> {
+    let day = find_day(number)?;
+    let input = source.load(number, name).map_err(|e| e.to_string())?;
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::BOTH.to_vec(),
+    };
+    let run = day.run(&input, &parts).map_err(|e| e.diagnostic())?;
+    for answer in &run.answers {
+        let value = &answer.value;
+        if value.contains('\n') {
+            println!("Day {number}, part {}:\n{value}", answer.part);
+        } else {
+            println!("Day {number}, part {}: {value}", answer.part);
+        }
+    }
+    Ok(())
+}
+
+fn elapsed(run: &Run) -> Duration {
+    run.parse + run.answers.iter().map(|a| a.elapsed).sum::<Duration>()
+}
+
+/// Answers that span several lines (the day 10 CRT) don't fit in the table, so
+/// they're printed underneath it instead
+fn cell(day: &Day, part: Part, value: &str, footnotes: &mut Vec<String>) -> String {
+    if value.contains('\n') {
+        footnotes.push(format!("Day {}, part {part}:\n{value}", day.number));
+        "(see below)".into()
+    } else {
+        value.into()
+    }
+}
+
+fn run_all(source: &Source, name: &str) -> Result<(), String> {
+    let mut rows = Vec::new();
+    let mut footnotes = Vec::new();
+    let mut failures = 0;
+    for day in registry::DAYS {
+        let result = match source.load(day.number, name) {
+            Err(e @ InputError::Missing(_)) => {
+                footnotes.push(format!("Day {}: {e}", day.number));
+                rows.push((day, ["-".into(), "-".into(), "-".into()]));
+                continue;
+            }
+            result => result.map_err(|e| e.to_string()),
+        };
+        let result =
+            result.and_then(|input| day.run(&input, &Part::BOTH).map_err(|e| e.to_string()));
+        let row = match result {
+            Ok(run) => {
+                let answers: Vec<_> = run
+                    .answers
+                    .iter()
+                    .map(|a| cell(day, a.part, &a.value, &mut footnotes))
+                    .collect();
+                let time = format!("{:.2?}", elapsed(&run));
+                [answers[0].clone(), answers[1].clone(), time]
+            }
+            Err(e) => {
+                failures += 1;
+                footnotes.push(format!("Day {}: {e}", day.number));
+                ["error".into(), "error".into(), "-".into()]
+            }
+        };
+        rows.push((day, row));
+    }
+
+    let width = |column: usize, header: &str| {
+        rows.iter()
+            .map(|(_, row)| row[column].len())
+            .chain([header.len()])
+            .max()
+            .unwrap()
+    };
+    let (w1, w2, w3) = (width(0, "Part 1"), width(1, "Part 2"), width(2, "Time"));
+    let title = registry::DAYS.iter().map(|d| d.title.len()).max().unwrap();
+    println!(
+        "{:>3}  {:title$}  {:w1$}  {:w2$}  {:>w3$}",
+        "Day", "Title", "Part 1", "Part 2", "Time"
+    );
+    for (day, [part1, part2, time]) in &rows {
+        println!(
+            "{:>3}  {:title$}  {part1:w1$}  {part2:w2$}  {time:>w3$}",
+            day.number, day.title
+        );
+    }
+    for footnote in footnotes {
+        println!("\n{footnote}");
+    }
+    match failures {
+        0 => Ok(()),
+        n => Err(format!("{n} day(s) failed")),
+    }
+}
+
+fn verify(dir: &Path, manifest: &Path) -> Result<(), String> {
+    let manifest = Manifest::load(manifest).map_err(|e| e.to_string())?;
+    let checks = manifest::verify(&manifest, dir);
+    let width = checks.iter().map(|c| c.entry.file.len()).max().unwrap_or(0);
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for check in &checks {
+        let entry = check.entry;
+        let (status, detail) = match &check.status {
+            Status::Pass => {
+                passed += 1;
+                ("pass", String::new())
+            }
+            Status::Fail(reason) => {
+                failed += 1;
+                let expected = manifest::escape(&entry.answer);
+                ("FAIL", format!("  expected {expected}, {reason}"))
+            }
+            Status::Missing(reason) => {
+                missing += 1;
+                ("missing", format!("  {reason}"))
+            }
+        };
+        println!(
+            "{status:7}  {:width$}  day {:>2}  part {}{detail}",
+            entry.file, entry.day, entry.part
+        );
+    }
+    println!("\n{passed} passed, {failed} failed, {missing} missing");
+    match failed {
+        0 => Ok(()),
+        n => Err(format!("{n} answer(s) didn't match")),
+    }
+}
+
+fn bench(
+    number: Option<u8>,
+    runs: usize,
+    source: &Source,
+    name: &str,
+    save: Option<&Path>,
+    baseline: Option<&Path>,
+    threshold: f64,
+) -> Result<(), String> {
+    let days = match number {
+        Some(number) => vec![find_day(number)?],
+        None => registry::DAYS.iter().collect(),
+    };
+    // read the baseline first, so a bad path doesn't waste a whole run
+    let baseline = baseline.map(Report::load).transpose()?;
+    if let Some(baseline) = &baseline {
+        if baseline.input != name {
+            let input = |name: &str| format!("{:?}", name);
+            return Err(format!(
+                "the baseline was run on {} inputs, not {}",
+                input(&baseline.input),
+                input(name)
+            ));
+        }
+    }
+
+    let mut timings = Vec::new();
+    let mut skipped = Vec::new();
+    for day in days {
+        let input = match source.load(day.number, name) {
+            Ok(input) => input,
+            // with --all, days without an input are left out
+            Err(e @ InputError::Missing(_)) if number.is_none() => {
+                skipped.push(format!("Day {}: {e}", day.number));
+                continue;
+            }
+            Err(e) => return Err(e.to_string()),
+        };
+        let timing = bench::bench(day, &input, runs).map_err(|e| e.to_string())?;
+        timings.push(timing);
+    }
+
+    let ns = |nanos: u64| format!("{:.2?}", Duration::from_nanos(nanos));
+    let rows: Vec<_> = timings
+        .iter()
+        .flat_map(|t| {
+            t.stages().map(|(stage, stats)| {
+                [
+                    t.day.to_string(),
+                    stage.to_string(),
+                    ns(stats.min),
+                    ns(stats.median),
+                    ns(stats.max),
+                ]
+            })
+        })
+        .collect();
+    let headers = ["Day", "Stage", "Min", "Median", "Max"];
+    let widths: Vec<_> = (0..headers.len())
+        .map(|i| {
+            rows.iter()
+                .map(|row| row[i].len())
+                .chain([headers[i].len()])
+                .max()
+                .unwrap()
+        })
+        .collect();
+    println!("{runs} run(s) per day");
+    for row in [headers.map(String::from)].iter().chain(&rows) {
+        println!(
+            "{:>w0$}  {:w1$}  {:>w2$}  {:>w3$}  {:>w4$}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            row[4],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+            w4 = widths[4],
+        );
+    }
+    for skipped in skipped {
+        println!("\n{skipped}");
+    }
+
+    let report = Report {
+        input: name.to_string(),
+        runs,
+        timings,
+    };
+    if let Some(path) = save {
+        report.save(path)?;
+        println!("\nsaved to {}", path.display());
+    }
+    let regressions = match baseline {
+        Some(baseline) => bench::compare(&baseline, &report, threshold),
+        None => return Ok(()),
+    };
+    if regressions.is_empty() {
+        println!("\nno regressions against the baseline");
+        return Ok(());
+    }
+    println!();
+    for r in &regressions {
+        println!(
+            "regression: day {}, {}: {:.2?} -> {:.2?}",
+            r.day, r.stage, r.before, r.after
+        );
+    }
+    Err(format!("{} stage(s) got slower", regressions.len()))
+}
+
+fn frames(source: &Source, kind: MarkerKind) -> Result<(), String> {
+    let input = source.load(6, input::REAL).map_err(|e| e.to_string())?;
+    let signal = input.trim_end_matches(['\r', '\n']);
+    println!(
+        "{:>5}  {:>7}  {:8}  {:>6}  {:>7}  {:>6}",
+        "Frame", "Offset", "Kind", "Marker", "Payload", "Length"
+    );
+    let mut count = 0;
+    for (i, frame) in day06::frames(signal.chars(), kind).enumerate() {
+        let kind = frame.kind.map_or("-".into(), |kind| kind.to_string());
+        println!(
+            "{:>5}  {:>7}  {kind:8}  {:>6}  {:>7}  {:>6}",
+            i + 1,
+            frame.offset,
+            frame.marker,
+            frame.payload,
+            frame.len()
+        );
+        count += 1;
+    }
+    println!(
+        "\n{count} frame(s), {} character(s)",
+        signal.chars().count()
+    );
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let command = match Command::parse(&args) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let result = match command {
+        Command::List => {
+            list();
+            Ok(())
+        }
+        Command::Run {
+            day,
+            part,
+            source,
+            name,
+        } => run(day, part, &source, &name),
+        Command::RunAll { source, name } => run_all(&source, &name),
+        Command::Verify { dir, manifest } => verify(&dir, &manifest),
+        Command::Bench {
+            day,
+            runs,
+            source,
+            name,
+            save,
+            baseline,
+            threshold,
+        } => bench(
+            day,
+            runs,
+            &source,
+            &name,
+            save.as_deref(),
+            baseline.as_deref(),
+            threshold,
+        ),
+        Command::Frames { source, kind } => frames(&source, kind),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
 }
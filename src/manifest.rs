@@ -0,0 +1,168 @@
+use std::{collections::HashMap, path::Path};
+
+use crate::{
+    error::{InputError, ManifestError},
+    input, registry,
+    solution::{Part, Run},
+};
+
+/// The manifest's name inside an input directory
+pub const FILE_NAME: &str = "answers.txt";
+
+/// The expected answer for one part of one day, on one input file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// The input's file name, relative to the input directory
+    pub file: String,
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+}
+
+/// Expected answers, one per line as `<file> <day> <part> <answer>`.
+///
+/// Everything after the part is the answer, with `\n` standing in for line
+/// breaks in multi-line answers. Blank lines and lines starting with `#` are
+/// ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Manifest {
+    pub entries: Vec<Entry>,
+}
+
+impl Manifest {
+    pub fn parse(text: &str) -> Result<Self, ManifestError> {
+        let entries = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+            .map(|(i, line)| parse_entry(line).ok_or_else(|| syntax(i + 1, line)))
+            .collect::<Result<_, _>>()?;
+        Ok(Manifest { entries })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, ManifestError> {
+        Self::parse(&input::file(path)?)
+    }
+}
+
+fn parse_entry(line: &str) -> Option<Entry> {
+    let (file, rest) = field(line)?;
+    let (day, rest) = field(rest)?;
+    let (part, rest) = field(rest)?;
+    let part = match part {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => return None,
+    };
+    let answer = unescape(rest.trim());
+    if answer.is_empty() {
+        return None;
+    }
+    Some(Entry {
+        file: file.into(),
+        day: day.parse().ok()?,
+        part,
+        answer,
+    })
+}
+
+/// The next whitespace-separated field and everything after it
+fn field(text: &str) -> Option<(&str, &str)> {
+    let text = text.trim_start();
+    let end = text.find(char::is_whitespace)?;
+    Some(text.split_at(end))
+}
+
+fn syntax(line: usize, text: &str) -> ManifestError {
+    ManifestError::Syntax {
+        line,
+        expected: "<file> <day> <part> <answer>".into(),
+        text: text.into(),
+    }
+}
+
+/// Writes line breaks as `\n` so an answer fits on one manifest line
+pub fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                unescaped.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                unescaped.push('\\');
+                chars.next();
+            }
+            _ => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+/// Editors like to strip trailing spaces from the manifest, and the day 10 CRT
+/// ends its lines with them, so those don't count
+fn same_answer(expected: &str, actual: &str) -> bool {
+    expected
+        .lines()
+        .map(str::trim_end)
+        .eq(actual.lines().map(str::trim_end))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    /// The solver gave a different answer, or couldn't give one at all
+    Fail(String),
+    /// There's no input to check the answer against
+    Missing(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct Check<'a> {
+    pub entry: &'a Entry,
+    pub status: Status,
+}
+
+/// Runs every solver named in the manifest on its input from `dir`. Each input
+/// is only solved once, however many of its parts are listed.
+pub fn verify<'a>(manifest: &'a Manifest, dir: &Path) -> Vec<Check<'a>> {
+    let mut runs: HashMap<(&str, u8), Result<Run, Status>> = HashMap::new();
+    manifest
+        .entries
+        .iter()
+        .map(|entry| {
+            let run = runs
+                .entry((&entry.file, entry.day))
+                .or_insert_with(|| solve(&dir.join(&entry.file), entry.day));
+            let status = match run {
+                Ok(run) => {
+                    let answer = run.answers.iter().find(|a| a.part == entry.part).unwrap();
+                    if same_answer(&entry.answer, &answer.value) {
+                        Status::Pass
+                    } else {
+                        Status::Fail(format!("got {}", escape(&answer.value)))
+                    }
+                }
+                Err(status) => status.clone(),
+            };
+            Check { entry, status }
+        })
+        .collect()
+}
+
+fn solve(path: &Path, number: u8) -> Result<Run, Status> {
+    let day = registry::find(number)
+        .ok_or_else(|| Status::Fail(format!("day {number} hasn't been solved")))?;
+    let input = input::file(path).map_err(|e| match e {
+        InputError::Missing(_) => Status::Missing(e.to_string()),
+        e => Status::Fail(e.to_string()),
+    })?;
+    day.run(&input, &Part::BOTH)
+        .map_err(|e| Status::Fail(e.to_string()))
+}
@@ -0,0 +1,154 @@
+use std::{
+    mem,
+    str::{FromStr, SplitWhitespace},
+};
+
+use crate::error::ParseError;
+
+/// A line of puzzle input which remembers where it came from, so that errors
+/// can point back at it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub day: u8,
+    /// 1-based line number
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// Every line of the input, numbered from 1
+pub fn lines(day: u8, input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(move |(i, text)| Line {
+        day,
+        number: i + 1,
+        text,
+    })
+}
+
+/// Runs of non-empty lines, separated by one or more blank lines
+pub fn blocks(day: u8, input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut blocks = Vec::new();
+    let mut current = Vec::new();
+    for line in lines(day, input) {
+        if !line.text.trim().is_empty() {
+            current.push(line);
+        } else if !current.is_empty() {
+            blocks.push(mem::take(&mut current));
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current);
+    }
+    blocks
+}
+
+/// An error for input that stops before the grammar is satisfied, pointing at
+/// the end of the last line
+pub fn end_of_input(day: u8, input: &str, expected: impl Into<String>) -> ParseError {
+    let last = lines(day, input).last().unwrap_or(Line {
+        day,
+        number: 1,
+        text: "",
+    });
+    last.error(last.end(), expected)
+}
+
+impl<'a> Line<'a> {
+    /// An error at `token`, which should be a slice of this line. Anything else
+    /// is reported at the end of the line.
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            day: self.day,
+            line: self.number,
+            column: self.column(token),
+            token: token.into(),
+            expected: expected.into(),
+            text: self.text.into(),
+        }
+    }
+
+    fn column(&self, token: &str) -> usize {
+        let offset = (token.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        if offset <= self.text.len() && self.text.is_char_boundary(offset) {
+            self.text[..offset].chars().count() + 1
+        } else {
+            self.text.chars().count() + 1
+        }
+    }
+
+    /// The empty token at the very end of the line
+    pub fn end(&self) -> &'a str {
+        &self.text[self.text.len()..]
+    }
+
+    /// The single character starting at byte `index`, as a token
+    pub fn char_at(&self, index: usize) -> &'a str {
+        let len = self.text[index..].chars().next().map_or(0, char::len_utf8);
+        &self.text[index..index + len]
+    }
+
+    pub fn split_once(
+        &self,
+        delimiter: &str,
+        expected: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        self.text
+            .split_once(delimiter)
+            .ok_or_else(|| self.error(self.end(), expected))
+    }
+
+    pub fn parse<T: FromStr>(&self, token: &'a str, expected: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, expected))
+    }
+
+    pub fn tokens(&self) -> Tokens<'a> {
+        Tokens {
+            line: *self,
+            iter: self.text.split_whitespace(),
+        }
+    }
+}
+
+/// The whitespace-separated tokens of a line
+pub struct Tokens<'a> {
+    line: Line<'a>,
+    iter: SplitWhitespace<'a>,
+}
+
+impl<'a> Tokens<'a> {
+    pub fn next(&mut self, expected: &str) -> Result<&'a str, ParseError> {
+        let line = self.line;
+        self.iter
+            .next()
+            .ok_or_else(|| line.error(line.end(), expected))
+    }
+
+    /// The next token, which must be exactly `keyword`
+    pub fn keyword(&mut self, keyword: &str) -> Result<(), ParseError> {
+        let expected = format!("{keyword:?}");
+        match self.next(&expected)? {
+            token if token == keyword => Ok(()),
+            token => Err(self.line.error(token, expected)),
+        }
+    }
+
+    pub fn parse<T: FromStr>(&mut self, expected: &str) -> Result<T, ParseError> {
+        let token = self.next(expected)?;
+        self.line.parse(token, expected)
+    }
+
+    /// Skips `n` tokens, whatever they are
+    pub fn skip(&mut self, n: usize, expected: &str) -> Result<(), ParseError> {
+        for _ in 0..n {
+            self.next(expected)?;
+        }
+        Ok(())
+    }
+
+    /// Succeeds only if every token has been consumed
+    pub fn end(mut self) -> Result<(), ParseError> {
+        match self.iter.next() {
+            Some(token) => Err(self.line.error(token, "end of line")),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Signed integers that can be used as point coordinates
+pub trait Coordinate:
+    Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MINUS_ONE: Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(impl Coordinate for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const MINUS_ONE: Self = -1;
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+        })*
+    };
+}
+
+coordinate!(i8, i16, i32, i64, i128, isize);
+
+/// A point, or the offset between two points, in the plane
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A point, or the offset between two points, in space
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: Coordinate> Point2<T> {
+    pub const ORIGIN: Self = Self::new(T::ZERO, T::ZERO);
+    pub const UNIT_X: Self = Self::new(T::ONE, T::ZERO);
+    pub const UNIT_Y: Self = Self::new(T::ZERO, T::ONE);
+
+    /// Steps to the four orthogonal neighbours
+    pub const NEIGHBORS_4: [Self; 4] = [
+        Self::new(T::ONE, T::ZERO),
+        Self::new(T::MINUS_ONE, T::ZERO),
+        Self::new(T::ZERO, T::ONE),
+        Self::new(T::ZERO, T::MINUS_ONE),
+    ];
+
+    /// Steps to all eight neighbours, diagonals included
+    pub const NEIGHBORS_8: [Self; 8] = [
+        Self::new(T::ONE, T::ZERO),
+        Self::new(T::ONE, T::ONE),
+        Self::new(T::ZERO, T::ONE),
+        Self::new(T::MINUS_ONE, T::ONE),
+        Self::new(T::MINUS_ONE, T::ZERO),
+        Self::new(T::MINUS_ONE, T::MINUS_ONE),
+        Self::new(T::ZERO, T::MINUS_ONE),
+        Self::new(T::ONE, T::MINUS_ONE),
+    ];
+
+    /// Taxicab distance: the number of orthogonal steps between two points
+    pub fn manhattan(self, other: Self) -> T {
+        let d = (self - other).abs();
+        d.x + d.y
+    }
+
+    /// Chessboard distance: the number of king's moves between two points
+    pub fn chebyshev(self, other: Self) -> T {
+        let d = (self - other).abs();
+        d.x.max(d.y)
+    }
+
+    pub fn abs(self) -> Self {
+        Self::new(self.x.abs(), self.y.abs())
+    }
+
+    /// The offset clamped to at most one step along each axis, which is the
+    /// single step that heads most directly towards it
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn min_each(self, other: Self) -> Self {
+        Self::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    pub fn max_each(self, other: Self) -> Self {
+        Self::new(self.x.max(other.x), self.y.max(other.y))
+    }
+}
+
+impl<T: Coordinate> Point3<T> {
+    pub const ORIGIN: Self = Self::new(T::ZERO, T::ZERO, T::ZERO);
+    pub const UNIT_X: Self = Self::new(T::ONE, T::ZERO, T::ZERO);
+    pub const UNIT_Y: Self = Self::new(T::ZERO, T::ONE, T::ZERO);
+    pub const UNIT_Z: Self = Self::new(T::ZERO, T::ZERO, T::ONE);
+
+    /// Steps to the six neighbours that share a face
+    pub const NEIGHBORS_6: [Self; 6] = [
+        Self::new(T::ONE, T::ZERO, T::ZERO),
+        Self::new(T::MINUS_ONE, T::ZERO, T::ZERO),
+        Self::new(T::ZERO, T::ONE, T::ZERO),
+        Self::new(T::ZERO, T::MINUS_ONE, T::ZERO),
+        Self::new(T::ZERO, T::ZERO, T::ONE),
+        Self::new(T::ZERO, T::ZERO, T::MINUS_ONE),
+    ];
+
+    pub fn manhattan(self, other: Self) -> T {
+        let d = (self - other).abs();
+        d.x + d.y + d.z
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        let d = (self - other).abs();
+        d.x.max(d.y).max(d.z)
+    }
+
+    pub fn abs(self) -> Self {
+        Self::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    pub fn min_each(self, other: Self) -> Self {
+        Self::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    pub fn max_each(self, other: Self) -> Self {
+        Self::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(p: Point2<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T> From<Point3<T>> for (T, T, T) {
+    fn from(p: Point3<T>) -> Self {
+        (p.x, p.y, p.z)
+    }
+}
+
+/// Componentwise arithmetic between points, and scaling by a single value
+macro_rules! operators {
+    ($point:ident { $($field:ident),* }) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                $point { $($field: self.$field + rhs.$field),* }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                $point { $($field: self.$field - rhs.$field),* }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($field: -self.$field),* }
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self {
+                $point { $($field: self.$field * rhs),* }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$field += rhs.$field;)*
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$field -= rhs.$field;)*
+            }
+        }
+
+        impl<T: MulAssign + Copy> MulAssign<T> for $point<T> {
+            fn mul_assign(&mut self, rhs: T) {
+                $(self.$field *= rhs;)*
+            }
+        }
+    };
+}
+
+operators!(Point2 { x, y });
+operators!(Point3 { x, y, z });
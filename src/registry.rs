@@ -0,0 +1,28 @@
+use crate::solution::Day;
+use crate::*;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>("Calorie Counting"),
+    Day::new::<day02::Day02>("Rock Paper Scissors"),
+    Day::new::<day03::Day03>("Rucksack Reorganization"),
+    Day::new::<day04::Day04>("Camp Cleanup"),
+    Day::new::<day05::Day05>("Supply Stacks"),
+    Day::new::<day06::Day06>("Tuning Trouble"),
+    Day::new::<day07::Day07>("No Space Left On Device"),
+    Day::new::<day08::Day08>("Treetop Tree House"),
+    Day::new::<day09::Day09>("Rope Bridge"),
+    Day::new::<day10::Day10>("Cathode-Ray Tube"),
+    Day::new::<day11::Day11>("Monkey in the Middle"),
+    Day::new::<day12::Day12>("Hill Climbing Algorithm"),
+    Day::new::<day13::Day13>("Distress Signal"),
+    Day::new::<day14::Day14>("Regolith Reservoir"),
+    Day::new::<day15::Day15>("Beacon Exclusion Zone"),
+    Day::new::<day16::Day16>("Proboscidea Volcanium"),
+    Day::new::<day17::Day17>("Pyroclastic Flow"),
+    Day::new::<day18::Day18>("Boiling Boulders"),
+    Day::new::<day25::Day25>("Full of Hot Air"),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,127 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use crate::error::{ParseError, RunError};
+
+/// Why a part couldn't be answered from input that parsed fine
+pub type SolveError = Box<dyn Error>;
+
+/// A single day's puzzle: parse the input once, then answer both parts from it
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<Self::Part1, SolveError>;
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError>;
+}
+
+/// Stand-in answer for parts that haven't been solved
+#[derive(Debug, Clone, Copy)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+    pub elapsed: Duration,
+}
+
+/// The outcome of running a day: how long parsing took and every requested answer
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub parse: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/// A type-erased [`Solution`], so that every day can be stored side by side
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    pub title: &'static str,
+    solve: fn(&str, &[Part]) -> Result<Run, RunError>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(title: &'static str) -> Self {
+        Day {
+            number: S::DAY,
+            title,
+            solve: solve::<S>,
+        }
+    }
+
+    pub fn run(&self, input: &str, parts: &[Part]) -> Result<Run, RunError> {
+        (self.solve)(input, parts)
+    }
+}
+
+fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Run, RunError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse = start.elapsed();
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let (value, elapsed) = match part {
+                Part::One => timed(|| S::part1(&parsed)),
+                Part::Two => timed(|| S::part2(&parsed)),
+            };
+            let value = value.map_err(|error| RunError::Solve {
+                day: S::DAY,
+                part,
+                error,
+            })?;
+            Ok(Answer {
+                part,
+                value,
+                elapsed,
+            })
+        })
+        .collect::<Result<_, RunError>>()?;
+    Ok(Run { parse, answers })
+}
+
+fn timed<T: Display>(
+    f: impl FnOnce() -> Result<T, SolveError>,
+) -> (Result<String, SolveError>, Duration) {
+    let start = Instant::now();
+    let answer = f();
+    let elapsed = start.elapsed();
+    (answer.map(|answer| answer.to_string()), elapsed)
+}
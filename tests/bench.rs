@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+use aoc_22::{
+    bench::{self, Report, Stats},
+    input, registry,
+};
+
+fn micros(samples: &[u64]) -> Vec<Duration> {
+    samples
+        .iter()
+        .map(|&us| Duration::from_micros(us))
+        .collect()
+}
+
+#[test]
+fn stats() {
+    let stats = Stats::new(&micros(&[5, 1, 3, 9, 4]));
+    assert_eq!((1000, 4000, 9000), (stats.min, stats.median, stats.max));
+}
+
+#[test]
+fn times_every_stage() {
+    let day = registry::find(2).unwrap();
+    let input = input::named(2, input::EXAMPLE).unwrap();
+    let timing = bench::bench(day, &input, 3).unwrap();
+    assert_eq!(2, timing.day);
+    for (_, stats) in timing.stages() {
+        assert!(stats.min <= stats.median && stats.median <= stats.max);
+    }
+}
+
+#[test]
+fn flags_regressions() {
+    let day = registry::find(2).unwrap();
+    let input = input::named(2, input::EXAMPLE).unwrap();
+    let timing = bench::bench(day, &input, 1).unwrap();
+    let baseline = Report {
+        input: input::EXAMPLE.into(),
+        runs: 1,
+        timings: vec![timing],
+    };
+    let json = serde_json::to_string(&baseline).unwrap();
+    assert_eq!(baseline, serde_json::from_str(&json).unwrap());
+
+    let mut slower = baseline.clone();
+    slower.timings[0].part2.median += 1_000_000;
+    let regressions = bench::compare(&baseline, &slower, 0.1);
+    assert_eq!(1, regressions.len());
+    assert_eq!("part 2", regressions[0].stage);
+    // getting faster is never a regression
+    assert!(bench::compare(&slower, &baseline, 0.1).is_empty());
+}
@@ -0,0 +1,47 @@
+use std::process::Command;
+
+fn aoc(args: &[&str]) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc-22"))
+        .args(args)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    (output.status.success(), stdout)
+}
+
+#[test]
+fn run_single_part() {
+    let (ok, stdout) = aoc(&["run", "1", "--part", "2", "--input", "tests/res/01.txt"]);
+    assert!(ok);
+    assert_eq!("Day 1, part 2: 205370\n", stdout);
+}
+
+#[test]
+fn bad_arguments() {
+    assert!(!aoc(&[]).0);
+    assert!(!aoc(&["run"]).0);
+    assert!(!aoc(&["run", "1", "--part", "3"]).0);
+    assert!(!aoc(&["run", "1", "--all"]).0);
+    assert!(!aoc(&["run", "19"]).0);
+}
+
+#[test]
+fn verify_bundled_answers() {
+    let (ok, stdout) = aoc(&["verify", "--manifest", "tests/res/answers.txt"]);
+    assert!(ok);
+    assert!(stdout.ends_with("67 passed, 0 failed, 0 missing\n"));
+}
+
+#[test]
+fn frame_table() {
+    let (ok, stdout) = aoc(&["frames", "tests/res/06ex.txt", "--size", "14"]);
+    assert!(ok);
+    let lines: Vec<_> = stdout.lines().collect();
+    assert_eq!(
+        "    2        5  size 14       14       11      25",
+        lines[2]
+    );
+    assert!(stdout.ends_with("2 frame(s), 30 character(s)\n"));
+    assert!(!aoc(&["frames"]).0);
+    assert!(!aoc(&["frames", "tests/res/06ex.txt", "--size", "0"]).0);
+}
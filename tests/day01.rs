@@ -1,25 +1,69 @@
-#![feature(binary_heap_drain_sorted)]
-
-use std::collections::BinaryHeap;
-
-const INPUT: &str = include_str!("res/01.txt");
+use aoc_22::{day01, error::ReadError, input};
 
 #[test]
 fn day1() {
-    let mut heap: BinaryHeap<u32> = INPUT
-        .split("\n\n")
-        .map(|raw| {
-            raw.lines()
-                .map(|calories| calories.parse::<u32>().unwrap())
-                .sum()
-        })
-        .collect();
+    let input = input::real(1).unwrap();
+    let inventory = day01::parse(&input).unwrap();
 
-    let &part1 = heap.peek().unwrap();
+    let part1 = day01::part1(&inventory);
     assert_eq!(68802, part1);
     println!("Day 1, part 1: {part1}");
 
-    let part2: u32 = heap.drain_sorted().take(3).sum();
+    let part2 = day01::part2(&inventory);
     assert_eq!(205370, part2);
-    println!("Day 2, part 2: {part2}");
+    println!("Day 1, part 2: {part2}");
+}
+
+#[test]
+fn top_elves() {
+    let inventory = day01::Inventory::from_groups(vec![
+        vec![1000, 2000, 3000],
+        vec![],
+        vec![6000],
+        vec![5000, 1000],
+        vec![7000],
+    ]);
+    let top: Vec<_> = inventory.top(2).iter().map(|elf| elf.index).collect();
+    assert_eq!(vec![4, 0], top);
+    // elves 0, 2 and 3 all carry 6000
+    let tied: Vec<_> = inventory
+        .top_with_ties(2)
+        .iter()
+        .map(|elf| elf.index)
+        .collect();
+    assert_eq!(vec![4, 0, 2, 3], tied);
+    assert_eq!(0, inventory.elves[1].items);
+    assert_eq!(2, inventory.elves[3].items);
+    assert_eq!(25000, inventory.top_total(10));
+    assert_eq!(0, day01::part1(&day01::parse("").unwrap()));
+
+    let report = inventory.report(2);
+    let expected = "\
+Rank    Elf  Items  Calories
+   1      5      1      7000
+   2      1      3      6000
+   2      3      1      6000
+   2      4      2      6000";
+    assert_eq!(expected, report);
+}
+
+#[test]
+fn streaming() {
+    let input = "1000\r\n2000\r\n\r\n\r\n\r\n4000\r\n\r\n5000\r\n6000\r\n\r\n";
+    let board = day01::read(input.as_bytes(), 2).unwrap();
+    assert_eq!(3, board.seen());
+    let inventory = board.into_inventory();
+    let indices: Vec<_> = inventory.elves.iter().map(|elf| elf.index).collect();
+    assert_eq!(vec![1, 2], indices);
+    assert_eq!(15000, inventory.top_total(2));
+
+    let real = input::real(1).unwrap();
+    let board = day01::read(real.as_bytes(), 3).unwrap();
+    assert_eq!(205370, board.into_inventory().top_total(3));
+
+    let error = day01::read("100\n\n\nabc\n".as_bytes(), 1).unwrap_err();
+    match error {
+        ReadError::Parse(e) => assert_eq!((4, "abc"), (e.line, e.token.as_str())),
+        e => panic!("unexpected error: {e}"),
+    }
 }
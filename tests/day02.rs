@@ -1,92 +1,103 @@
-const INPUT: &str = include_str!("res/02.txt");
+use aoc_22::{day02, input};
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-enum Shape {
-    Rock,
-    Paper,
-    Scissors,
+#[test]
+fn part1() {
+    let input = input::real(2).unwrap();
+    let guide = day02::parse(&input).unwrap();
+    let ans = day02::part1(&guide);
+    println!("Day 2, part 1: {ans}");
+    assert_eq!(11386, ans);
 }
 
-impl Shape {
-    fn parse(token: &str) -> Self {
-        use Shape::*;
-        let char = token.chars().next().unwrap().to_ascii_lowercase();
-        match char {
-            'a' | 'x' => Rock,
-            'b' | 'y' => Paper,
-            'c' | 'z' => Scissors,
-            _ => panic!("bad input value!"),
-        }
-    }
-
-    fn score(&self) -> u32 {
-        use Shape::*;
-        match &self {
-            Rock => 1,
-            Paper => 2,
-            Scissors => 3,
-        }
-    }
-
-    fn beats(&self) -> Self {
-        use Shape::*;
-        match &self {
-            Rock => Scissors,
-            Paper => Rock,
-            Scissors => Paper,
-        }
-    }
+#[test]
+fn part2() {
+    let input = input::real(2).unwrap();
+    let guide = day02::parse(&input).unwrap();
+    let ans = day02::part2(&guide);
+    println!("Day 2, part 2: {ans}");
+    assert_eq!(13600, ans);
 }
 
-struct Round(Shape, Shape);
+#[test]
+fn other_games() {
+    use day02::{Game, Outcome, Shape};
 
-impl Round {
-    fn parse_part1(input: &str) -> Self {
-        let (opponent, player) = input.split_once(" ").unwrap();
-        Round(Shape::parse(opponent), Shape::parse(player))
+    let game = day02::Game::rock_paper_scissors_lizard_spock();
+    let shape = |name: &str| Shape(game.shapes.iter().position(|s| s == name).unwrap());
+    let wins = [
+        ("Scissors", "Paper"),
+        ("Paper", "Rock"),
+        ("Rock", "Lizard"),
+        ("Lizard", "Spock"),
+        ("Spock", "Scissors"),
+        ("Scissors", "Lizard"),
+        ("Lizard", "Paper"),
+        ("Paper", "Spock"),
+        ("Spock", "Rock"),
+        ("Rock", "Scissors"),
+    ];
+    for (a, b) in wins {
+        assert_eq!(Outcome::Win, game.outcome(shape(a), shape(b)), "{a} vs {b}");
+        assert_eq!(
+            Outcome::Loss,
+            game.outcome(shape(b), shape(a)),
+            "{b} vs {a}"
+        );
     }
 
-    fn parse_part2(input: &str) -> Self {
-        let (opponent, player) = input.split_once(" ").unwrap();
-        let opponent = Shape::parse(opponent);
-        let player = match player.chars().next().unwrap().to_ascii_lowercase() {
-            'x' => opponent.beats(),
-            'y' => opponent,
-            'z' => opponent.beats().beats(),
-            _ => panic!(),
-        };
-        return Round(opponent, player);
-    }
+    // every shape in RPS-101 beats exactly 50 others
+    let game = Game::numbered(101);
+    let beaten = (0..101).filter(|&i| game.beats(Shape(7), Shape(i))).count();
+    assert_eq!(50, beaten);
 
-    fn score(&self) -> u32 {
-        let outcome = if &self.0.beats() == &self.1 {
-            0
-        } else if &self.0 == &self.1 {
-            3
-        } else {
-            6
-        };
-        let shape_bonus = &self.1.score();
-        return outcome + shape_bonus;
-    }
+    let game = Game::rock_paper_scissors_lizard_spock()
+        .with_symbols(&["r", "k", "p", "l", "s"], &["R", "K", "P", "L", "S"])
+        .with_outcome_scores(0, 1, 2);
+    let guide = day02::parse_with(&game, "s p\nk K\nL r").unwrap();
+    let scores: Vec<_> = guide
+        .iter()
+        .map(|&entry| day02::Round::part1(&game, entry).score(&game))
+        .collect();
+    assert_eq!(vec![3, 3, 3], scores);
 }
 
 #[test]
-fn part1() {
-    let ans: u32 = INPUT
-        .lines()
-        .map(Round::parse_part1)
-        .map(|r| r.score())
-        .sum();
-    println!("Day 2, part 1: {ans}");
-}
+fn interpretations() {
+    use day02::{Game, Outcome, Reading, Shape};
 
-#[test]
-fn part2() {
-    let ans: u32 = INPUT
-        .lines()
-        .map(Round::parse_part2)
-        .map(|r| r.score())
-        .sum();
-    println!("Day 2, part 1: {ans}");
+    let game = Game::rock_paper_scissors();
+    let input = input::named(2, input::EXAMPLE).unwrap();
+    let guide = day02::parse(&input).unwrap();
+    let ranked = day02::interpretations(&game, &guide, usize::MAX).unwrap();
+    assert_eq!(12, ranked.len());
+    assert!(ranked.windows(2).all(|w| w[0].score >= w[1].score));
+
+    // the puzzle's two readings are among them
+    let part1 = Reading::Shapes(vec![Shape(0), Shape(1), Shape(2)]);
+    let part1 = ranked.iter().find(|i| i.reading == part1).unwrap();
+    assert_eq!(
+        (15, 1, 1, 1),
+        (part1.score, part1.wins, part1.draws, part1.losses)
+    );
+    let part2 = Reading::Outcomes(Outcome::ALL.to_vec());
+    let part2 = ranked.iter().find(|i| i.reading == part2).unwrap();
+    assert_eq!(
+        (12, 1, 1, 1),
+        (part2.score, part2.wins, part2.draws, part2.losses)
+    );
+
+    // only the ranking asked for is kept
+    let best = day02::interpretations(&game, &guide, 3).unwrap();
+    assert_eq!(ranked[..3], best[..]);
+
+    let report = day02::report(&game, &guide, usize::MAX).unwrap();
+    println!("{report}");
+    assert_eq!(13, report.lines().count());
+    assert!(report.contains("X=Rock, Y=Paper, Z=Scissors"));
+
+    // 13! readings would take far too long to score
+    let game = Game::numbered(13);
+    let guide = day02::parse_with(&game, "1 13\n7 2").unwrap();
+    let error = day02::interpretations(&game, &guide, 1).unwrap_err();
+    assert_eq!(13, error.columns);
 }
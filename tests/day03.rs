@@ -1,50 +1,119 @@
-use std::collections::HashSet;
-
-const INPUT: &str = include_str!("res/03.txt");
-
-struct Rucksack(Vec<HashSet<char>>);
-
-impl Rucksack {
-    fn new(input: &str) -> Self {
-        let (first, second) = input.split_at(input.len() / 2);
-        let first = first.chars().collect();
-        let second = second.chars().collect();
-        return Rucksack(vec![first, second]);
-    }
-
-    fn from_group(items: &[&str]) -> Self {
-        let items = items.iter().map(|line| line.chars().collect()).collect();
-        Rucksack(items)
-    }
-
-    fn priority(self) -> u32 {
-        let common = self.0.into_iter().reduce(|a, b| {
-            return a.intersection(&b).cloned().collect();
-        });
-        let &c = common.unwrap().iter().next().unwrap();
-        return Self::priority_for(c);
-    }
-
-    fn priority_for(c: char) -> u32 {
-        if ('a'..='z').contains(&c) {
-            1 + c as u32 - 'a' as u32
-        } else {
-            27 + c as u32 - 'A' as u32
-        }
-    }
-}
+use aoc_22::{
+    day03::{self, Analysis, GroupError, ItemSet, Source},
+    input,
+};
 
 #[test]
 fn part1() {
-    let rucksacks: Vec<_> = INPUT.lines().map(Rucksack::new).collect();
-    let ans: u32 = rucksacks.into_iter().map(Rucksack::priority).sum();
+    let input = input::real(3).unwrap();
+    let rucksacks = day03::parse(&input).unwrap();
+    let ans = day03::part1(&rucksacks).unwrap();
     println!("Day 3, part 1: {ans}")
 }
 
 #[test]
 fn part2() {
-    let lines: Vec<_> = INPUT.lines().collect();
-    let rucksacks: Vec<_> = lines.chunks(3).map(Rucksack::from_group).collect();
-    let ans: u32 = rucksacks.into_iter().map(Rucksack::priority).sum();
+    let input = input::real(3).unwrap();
+    let rucksacks = day03::parse(&input).unwrap();
+    let ans = day03::part2(&rucksacks).unwrap();
     println!("Day 3, part 2: {ans}")
 }
+
+#[test]
+fn item_sets() {
+    let a: ItemSet = "abcZ".chars().collect();
+    let b: ItemSet = "cZz".chars().collect();
+    assert_eq!("cZ", (a & b).to_string());
+    assert_eq!("abczZ", (a | b).to_string());
+    assert_eq!("ab", (a - b).to_string());
+    assert!(a.contains('Z') && !a.contains('z'));
+    assert_eq!(None, (a & b).single());
+
+    // anything that isn't an item type is skipped
+    let c: ItemSet = "a1 b!".chars().collect();
+    assert_eq!("ab", c.to_string());
+    let mut d = ItemSet::EMPTY;
+    assert!(!d.insert('1') && d.is_empty());
+}
+
+#[test]
+fn ambiguous_and_missing_items() {
+    let rucksacks = day03::parse("abab\nabcd\nabcdxy").unwrap();
+    // both a and b are in each half of the first rucksack
+    let error = day03::part1(&rucksacks).unwrap_err();
+    assert_eq!(Source::Rucksack { line: 1 }, error.source);
+    assert_eq!(
+        "the rucksack on line 1 has 2 items in common (ab)",
+        error.to_string()
+    );
+    assert_eq!(0, rucksacks[1].priority().unwrap_err().common.len());
+
+    let error = day03::part2(&rucksacks).unwrap_err();
+    assert_eq!(
+        "group 1 (lines 1, 2, 3) has 2 items in common (ab)",
+        error.to_string()
+    );
+
+    // a fourth rucksack starts a group that's never finished
+    let rucksacks = day03::parse("abab\nabcd\nabcdxy\nxyxy").unwrap();
+    let error = day03::part2(&rucksacks).unwrap_err();
+    assert_eq!(
+        GroupError::Incomplete {
+            index: 1,
+            lines: vec![4],
+            size: 3
+        },
+        error
+    );
+    assert_eq!(
+        "group 2 (lines 4) has 1 rucksack(s) rather than 3",
+        error.to_string()
+    );
+
+    let error = day03::parse("abc").unwrap_err();
+    assert_eq!((1, 4), (error.line, error.column));
+    let error = day03::parse("ab1d").unwrap_err();
+    assert_eq!((3, "1"), (error.column, error.token.as_str()));
+}
+
+#[test]
+fn analysis() {
+    let input = input::named(3, input::EXAMPLE).unwrap();
+    let rucksacks = day03::parse(&input).unwrap();
+    let analysis = Analysis::new(&rucksacks, 3).unwrap();
+    let candidates: Vec<_> = analysis
+        .groups
+        .iter()
+        .map(|g| g.candidates.to_string())
+        .collect();
+    assert_eq!(["r", "Z"], candidates.as_slice());
+    let lines: Vec<_> = analysis.misplaced.iter().map(|m| m.line).collect();
+    assert_eq!([1, 2, 3, 4, 5, 6], lines.as_slice());
+    assert_eq!(vec![('p', 16)], analysis.misplaced[0].items);
+
+    // r is in all of the first group and one rucksack of the second
+    let r = analysis.catalog.iter().find(|c| c.item == 'r').unwrap();
+    assert_eq!((18, 2), (r.priority, r.groups));
+    assert_eq!(4, r.rucksacks);
+
+    // in pairs, the example has no single badge per group
+    let pairs = Analysis::new(&rucksacks, 2).unwrap();
+    assert_eq!(3, pairs.groups.len());
+    assert_eq!("frsFM", pairs.groups[0].candidates.to_string());
+    assert!(pairs.table().starts_with("Group  Lines"));
+    let json: serde_json::Value = serde_json::from_str(&pairs.to_json()).unwrap();
+    assert_eq!("frsFM", json["groups"][0]["candidates"]);
+    assert_eq!(
+        serde_json::json!(["p", 16]),
+        json["misplaced"][0]["items"][0]
+    );
+
+    // six rucksacks can't be split into fours, or groups of none
+    let error = Analysis::new(&rucksacks, 4).unwrap_err();
+    assert_eq!(
+        "group 2 (lines 5, 6) has 2 rucksack(s) rather than 4",
+        error.to_string()
+    );
+    assert_eq!(Err(GroupError::ZeroSize), day03::badges(&rucksacks, 0));
+    assert_eq!(Err(GroupError::ZeroSize), Analysis::new(&rucksacks, 0));
+}
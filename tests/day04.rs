@@ -1,57 +1,59 @@
-const INPUT: &str = include_str!("res/04.txt");
-
-type Assignment = (u32, u32);
-
-fn parse_line(line: &str) -> (Assignment, Assignment) {
-    let (l, r) = line.split_once(",").unwrap();
-    return (parse_assignment(l), parse_assignment(r));
-}
-
-fn parse_assignment(pair: &str) -> Assignment {
-    let (l, r) = pair.split_once("-").unwrap();
-    return (l.parse().unwrap(), r.parse().unwrap());
-}
-
-fn fully_contains(a: &Assignment, b: &Assignment) -> bool {
-    if a.0 <= b.0 && a.1 >= b.1 {
-        return true;
-    }
-    if b.0 <= a.0 && b.1 >= a.1 {
-        return true;
-    }
-    return false;
-}
-
-fn any_overlap(a: &Assignment, b: &Assignment) -> bool {
-    overlaps(a, b) || overlaps(b, a)
-}
-
-fn overlaps(a: &Assignment, b: &Assignment) -> bool {
-    if a.0 <= b.0 && b.0 <= a.1 {
-        return true;
-    }
-    if a.0 <= b.1 && b.1 <= a.1 {
-        return true;
-    }
-    return false;
-}
+use aoc_22::{
+    day04::{self, Roster},
+    input,
+    interval::Interval,
+};
 
 #[test]
 fn part1() {
-    let ans = INPUT
-        .lines()
-        .map(parse_line)
-        .filter(|(p1, p2)| fully_contains(p1, p2))
-        .count();
+    let input = input::real(4).unwrap();
+    let pairs = day04::parse(&input).unwrap();
+    let ans = day04::part1(&pairs);
     println!("Day 4, part 1: {ans}");
 }
 
 #[test]
 fn part2() {
-    let ans = INPUT
-        .lines()
-        .map(parse_line)
-        .filter(|(p1, p2)| any_overlap(p1, p2))
-        .count();
+    let input = input::real(4).unwrap();
+    let pairs = day04::parse(&input).unwrap();
+    let ans = day04::part2(&pairs);
     println!("Day 4, part 2: {ans}");
 }
+
+#[test]
+fn roster() {
+    let input = input::named(4, input::EXAMPLE).unwrap();
+    let roster = Roster::new(&day04::parse(&input).unwrap());
+    assert_eq!(12, roster.elves.len());
+    assert_eq!(Interval::new(2, 9), roster.span());
+    let counts: Vec<_> = (2..=9).map(|s| roster.count(s)).collect();
+    assert_eq!(vec![4, 5, 7, 7, 8, 6, 4, 1], counts);
+    // the runs agree with counting every section
+    for (run, count) in roster.coverage() {
+        for section in run.start..=run.end {
+            assert_eq!(roster.count(section), count);
+        }
+    }
+    assert!(roster.uncovered().is_empty());
+    // only 5-7,7-9's second elf covers section 9
+    assert_eq!(11, roster.redundant().len());
+    assert!(!roster.redundant().contains(&5));
+    // 2-8 and 7-9
+    assert_eq!(vec![5, 6], roster.minimal_cover());
+}
+
+#[test]
+fn roster_gaps() {
+    let roster = Roster::new(&day04::parse("1-2,4-5\n4-4,8-9\n5-5,1-1").unwrap());
+    assert_eq!("{3-3, 6-7}", roster.uncovered().to_string());
+    assert_eq!(vec![1, 2, 4, 5], roster.redundant());
+    assert_eq!(vec![0, 1, 3], roster.minimal_cover());
+    let empty: Vec<_> = roster
+        .coverage()
+        .into_iter()
+        .filter(|&(_, count)| count == 0)
+        .map(|(run, _)| run.to_string())
+        .collect();
+    assert_eq!(vec!["3-3", "6-7"], empty);
+    assert!(roster.report().contains("Uncovered: 3-3, 6-7"));
+}
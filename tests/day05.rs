@@ -1,65 +1,250 @@
-const STACKS: &str = include_str!("res/05/stacks.txt");
-const PROCEDURE: &str = include_str!("res/05/proc.txt");
+use aoc_22::{
+    day05::{
+        self, Crane, CrateMover9000, CrateMover9001, Limited, MoveError, MoveErrorKind, Replay,
+        Stacks, Target,
+    },
+    input, parse, registry,
+    solution::Part,
+};
 
-struct Instruction {
-    quantity: usize,
-    source: usize,
-    destination: usize,
+#[test]
+fn day5() {
+    let input = input::real(5).unwrap();
+    let (stacks, instructions) = day05::parse(&input).unwrap();
+
+    // part 1
+    let part1 = day05::part1(&stacks, &instructions).unwrap();
+    println!("Day 5, part 1: {part1}");
+
+    // part 2
+    let part2 = day05::part2(&stacks, &instructions).unwrap();
+    println!("Day 5, part 2: {part2}");
 }
 
-impl Instruction {
-    fn parse(instr: &str) -> Self {
-        let tokens: Vec<_> = instr
-            .split_whitespace()
-            .filter_map(|t| match t.parse::<usize>() {
-                Ok(n) => Some(n),
-                Err(_) => None,
-            })
-            .collect();
-        return Instruction {
-            quantity: tokens[0],
-            source: tokens[1] - 1,
-            destination: tokens[2] - 1,
-        };
-    }
+fn drawing(text: &str) -> Result<Stacks, aoc_22::error::ParseError> {
+    Stacks::parse(&parse::blocks(5, text)[0])
+}
 
-    fn move_single(&self, stacks: &mut [Vec<char>]) {
-        for _ in 0..self.quantity {
-            let popped = stacks[self.source].pop().unwrap();
-            stacks[self.destination].push(popped);
-        }
+#[test]
+fn round_trip() {
+    for name in [input::REAL, input::EXAMPLE] {
+        let input = input::named(5, name).unwrap();
+        let (stacks, _) = day05::parse(&input).unwrap();
+        let (drawn, _) = input.split_once("\n\n").unwrap();
+        assert_eq!(drawn, stacks.to_string());
     }
+    let stacks = drawing("    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 ").unwrap();
+    assert_eq!(
+        vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']],
+        stacks.0
+    );
+    assert_eq!("NDP", stacks.tops());
+}
 
-    fn move_bulk(&self, stacks: &mut [Vec<char>]) {
-        let index = stacks[self.source].len() - self.quantity;
-        let mut to_move = stacks[self.source].drain(index..).collect();
-        stacks[self.destination].append(&mut to_move);
-    }
+#[test]
+fn ragged_drawings() {
+    // no trailing spaces, extra trailing spaces and an empty stack
+    let stacks = drawing("    [D]\n[N] [C]          \n[Z] [M]\n 1   2   3").unwrap();
+    assert_eq!(vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec![]], stacks.0);
+    assert_eq!("ND", stacks.tops());
+    assert_eq!(
+        "    [D]    \n[N] [C]    \n[Z] [M]    \n 1   2   3 ",
+        stacks.to_string()
+    );
+
+    let many = Stacks((0..12).map(|i| vec![(b'A' + i) as char]).collect());
+    assert_eq!(many, drawing(&many.to_string()).unwrap());
 }
 
 #[test]
-fn day5() {
-    let mut stacks: Vec<_> = STACKS
-        .lines()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .collect();
-    let instructions: Vec<_> = PROCEDURE.lines().map(Instruction::parse).collect();
+fn drawing_errors() {
+    let error = drawing("[A] [B]\n 1   3").unwrap_err();
+    assert_eq!((2, 6), (error.line, error.column));
+    assert_eq!("stack number 2", error.expected);
 
-    // part 1
-    let mut stack_copy = stacks.clone();
-    instructions
-        .iter()
-        .for_each(|instr| instr.move_single(&mut stack_copy));
-    let part1: String = stack_copy
-        .iter()
-        .map(|stack| *stack.last().unwrap())
-        .collect();
-    println!("Day 5, part 1: {part1}");
+    let error = drawing("[A] [B] [C]\n 1   2").unwrap_err();
+    assert_eq!((1, 9), (error.line, error.column));
 
-    // part 2
-    instructions
-        .iter()
-        .for_each(|instr| instr.move_bulk(&mut stacks));
-    let part2: String = stacks.iter().map(|stack| *stack.last().unwrap()).collect();
-    println!("Day 5, part 2: {part2}");
+    let error = drawing("[A] B\n 1   2").unwrap_err();
+    assert_eq!((5, "B"), (error.column, error.token.as_str()));
+
+    let error = drawing("[A]x[B]\n 1   2").unwrap_err();
+    assert_eq!(
+        (4, "a space between stacks"),
+        (error.column, error.expected.as_str())
+    );
+
+    // C has nothing under it
+    let error = drawing("    [C]\n[A]    \n 1   2").unwrap_err();
+    assert_eq!(
+        (1, 5, "[C]"),
+        (error.line, error.column, error.token.as_str())
+    );
+
+    // a drawing needs at least its footer
+    let error = Stacks::parse(&[]).unwrap_err();
+    assert_eq!("a stack-number footer", error.expected);
+}
+
+#[test]
+fn cranes() {
+    let input = input::named(5, input::EXAMPLE).unwrap();
+    let (stacks, instructions) = day05::parse(&input).unwrap();
+    let tops = |crane: &dyn Crane| crane.run(&stacks, &instructions).unwrap().tops();
+    assert_eq!("CMZ", tops(&CrateMover9000));
+    assert_eq!("MCD", tops(&CrateMover9001));
+    assert_eq!("CMZ", tops(&Limited { capacity: 1 }));
+    assert_eq!("MCD", tops(&Limited { capacity: 3 }));
+
+    // moving 3 crates two at a time: the top two keep their order and land
+    // under the last one
+    let stacks = Stacks(vec![vec!['A', 'B', 'C'], vec![]]);
+    let (_, procedure) = day05::parse("[A]\n 1\n\nmove 3 from 1 to 2").unwrap();
+    let moved = Limited { capacity: 2 }.run(&stacks, &procedure).unwrap();
+    assert_eq!(vec!['B', 'C', 'A'], moved.0[1]);
+}
+
+#[test]
+fn checked_moves() {
+    let input = input::named(5, input::EXAMPLE).unwrap();
+    let (stacks, mut instructions) = day05::parse(&input).unwrap();
+    instructions[2].quantity = 5;
+    let error = CrateMover9001.run(&stacks, &instructions).unwrap_err();
+    let underflow = MoveErrorKind::Underflow {
+        stack: 1,
+        wanted: 5,
+        available: 2,
+    };
+    assert_eq!(
+        MoveError {
+            index: 2,
+            kind: underflow
+        },
+        error
+    );
+    assert_eq!(
+        "instruction 3: can't move 5 crate(s) from stack 2, which holds 2",
+        error.to_string()
+    );
+
+    instructions[2].quantity = 1;
+    instructions[3].destination = 3;
+    let error = CrateMover9000.run(&stacks, &instructions).unwrap_err();
+    let missing = MoveErrorKind::MissingStack { stack: 3, count: 3 };
+    assert_eq!(
+        MoveError {
+            index: 3,
+            kind: missing
+        },
+        error
+    );
+
+    // the answers don't paper over a procedure that can't be carried out
+    let input = input.replace("move 2 from 2 to 1", "move 5 from 2 to 1");
+    let error = registry::find(5)
+        .unwrap()
+        .run(&input, &Part::BOTH)
+        .unwrap_err();
+    assert_eq!(
+        "day 5, part 1: instruction 3: can't move 5 crate(s) from stack 2, which holds 2",
+        error.to_string()
+    );
+}
+
+#[test]
+fn replay() {
+    let input = input::named(5, input::EXAMPLE).unwrap();
+    let (stacks, instructions) = day05::parse(&input).unwrap();
+    let mut replay = Replay::new(&CrateMover9000, &stacks, &instructions);
+    assert!(!replay.step_back());
+    assert!(replay.step_forward().unwrap());
+    assert_eq!("ZND MC P", replay.current().summary());
+    assert!(replay.step_forward().unwrap());
+    assert_eq!("- MC PDNZ", replay.current().summary());
+    assert!(replay.step_back());
+    assert_eq!(
+        (1, "DCP".to_string()),
+        (replay.position(), replay.current().tops())
+    );
+    assert_eq!("CMZ", replay.after(4).unwrap().tops());
+    assert_eq!(1, replay.position());
+    while replay.step_forward().unwrap() {}
+    assert!(replay.is_finished());
+    assert_eq!("CMZ", replay.current().tops());
+
+    // Z only reaches the top on the second instruction
+    assert_eq!(Some(1), replay.first_on_top('Z').unwrap());
+    assert_eq!(None, replay.first_on_top('X').unwrap());
+    // P and N start on top and never come back once they're covered
+    assert_eq!(None, replay.first_on_top('P').unwrap());
+    assert_eq!(None, replay.first_on_top('N').unwrap());
+    // D goes straight from the top of stack 2 to the top of stack 1, which
+    // uncovers C
+    assert_eq!(Some(0), replay.first_on_top('D').unwrap());
+    assert_eq!(Some(0), replay.first_on_top('C').unwrap());
+    assert_eq!(Some(2), replay.first_on_top('M').unwrap());
+    assert_eq!("move 1 from 1 to 2", instructions[3].to_string());
+}
+
+#[test]
+fn traces() {
+    let input = input::named(5, input::EXAMPLE).unwrap();
+    let (stacks, instructions) = day05::parse(&input).unwrap();
+    let single = Replay::new(&CrateMover9000, &stacks, &instructions)
+        .trace()
+        .unwrap();
+    let bulk = Replay::new(&CrateMover9001, &stacks, &instructions)
+        .trace()
+        .unwrap();
+    assert_eq!(5, single.len());
+    assert_eq!("   1  move 1 from 2 to 1      ZND MC P", single[1]);
+    // moving a single crate is the same either way
+    assert_eq!(Some(2), day05::divergence(&single, &bulk));
+    assert_eq!(None, day05::divergence(&single, &single));
+
+    let mut broken = instructions.clone();
+    broken[1].quantity = 4;
+    let mut replay = Replay::new(&CrateMover9000, &stacks, &broken);
+    assert!(replay.step_forward().unwrap());
+    assert_eq!(1, replay.step_forward().unwrap_err().index);
+    assert_eq!(1, replay.position());
+    assert!(replay.trace().is_err());
+}
+
+#[test]
+fn planner() {
+    let input = input::named(5, input::EXAMPLE).unwrap();
+    let (stacks, instructions) = day05::parse(&input).unwrap();
+
+    // the example's own procedure isn't the shortest way to get CMZ on top
+    let target = Target::Tops("CMZ".into());
+    let plan = day05::plan(&CrateMover9000, &stacks, &target, 4).unwrap();
+    assert!(plan.len() < instructions.len());
+    let text = format!("{stacks}\n\n{}", day05::procedure(&plan));
+    let (parsed, procedure) = day05::parse(&text).unwrap();
+    assert_eq!(plan, procedure);
+    assert!(target.reached(&CrateMover9000.run(&parsed, &procedure).unwrap()));
+
+    // a whole arrangement, for each model of crane
+    let end = CrateMover9001.run(&stacks, &instructions).unwrap();
+    let target = Target::Stacks(end.clone());
+    for crane in [&CrateMover9000 as &dyn Crane, &CrateMover9001] {
+        let plan = day05::plan(crane, &stacks, &target, 4).unwrap();
+        assert!(plan.len() <= 4);
+        assert_eq!(end, crane.run(&stacks, &plan).unwrap());
+    }
+
+    assert_eq!(
+        Some(vec![]),
+        day05::plan(&CrateMover9000, &stacks, &Target::Tops("NDP".into()), 0)
+    );
+    // there is no crate X, and D can't get on top without a move
+    assert_eq!(
+        None,
+        day05::plan(&CrateMover9000, &stacks, &Target::Tops("XDP".into()), 3)
+    );
+    assert_eq!(
+        None,
+        day05::plan(&CrateMover9000, &stacks, &Target::Tops("NCD".into()), 0)
+    );
 }
@@ -1,39 +1,121 @@
-use std::collections::HashSet;
+use std::io::Cursor;
 
-const INPUT: &str = include_str!("res/06.txt");
+use aoc_22::{
+    day06::{self, Frame, MarkerKind, NoMarker},
+    input, registry,
+    solution::Part,
+};
 
 #[test]
 fn part1() {
-    let stream: Vec<_> = INPUT.chars().collect();
-    let ans = stream
-        .windows(4)
-        .enumerate()
-        .find_map(|(i, window)| {
-            let unique: HashSet<char> = window.iter().cloned().collect();
-            if unique.len() == 4 {
-                Some(i + 4)
-            } else {
-                None
-            }
-        })
-        .unwrap();
+    let input = input::real(6).unwrap();
+    let ans = day06::part1(&input).unwrap();
     println!("Day 6, part 1: {ans}");
 }
 
 #[test]
 fn part2() {
-    let stream: Vec<_> = INPUT.chars().collect();
-    let ans = stream
-        .windows(14)
-        .enumerate()
-        .find_map(|(i, window)| {
-            let unique: HashSet<char> = window.iter().cloned().collect();
-            if unique.len() == 14 {
-                Some(i + 14)
-            } else {
-                None
-            }
-        })
-        .unwrap();
+    let input = input::real(6).unwrap();
+    let ans = day06::part2(&input).unwrap();
     println!("Day 6, part 2: {ans}");
 }
+
+#[test]
+fn every_marker() {
+    let examples = [
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+    ];
+    for (stream, packet, message) in examples {
+        assert_eq!(Some(packet), day06::find_marker(stream, 4));
+        assert_eq!(Some(message), day06::find_marker(stream, 14));
+    }
+    let all: Vec<_> = day06::markers("abcabcaab".chars(), 3).collect();
+    assert_eq!(vec![3, 4, 5, 6, 7], all);
+    assert_eq!(
+        vec![1, 2, 3, 4],
+        day06::markers("aaaa".chars(), 1).collect::<Vec<_>>()
+    );
+    assert_eq!(None, day06::find_marker("abab", 3));
+
+    // a stream too short for a marker has no answer rather than a made-up one
+    let short = NoMarker(MarkerKind::Packet);
+    assert_eq!(Err(short), day06::part1("abc"));
+    assert_eq!("no start-of-packet marker", short.to_string());
+    let error = registry::find(6)
+        .unwrap()
+        .run("abcd\n", &Part::BOTH)
+        .unwrap_err();
+    assert_eq!(
+        "day 6, part 2: no start-of-message marker",
+        error.to_string()
+    );
+}
+
+#[test]
+fn streaming() {
+    let input = input::real(6).unwrap();
+    for size in [4, 14, 20] {
+        let read = day06::read_markers(Cursor::new(input.as_bytes()), size).unwrap();
+        let all: Vec<_> = day06::markers(input.chars(), size).collect();
+        assert_eq!(all, read);
+    }
+
+    // positions count characters, however many bytes they take
+    let stream = "ééàüéö€🦀";
+    assert_eq!(Some(6), day06::find_marker(stream, 4));
+    assert_eq!(
+        vec![6, 7, 8],
+        day06::read_markers(Cursor::new(stream.as_bytes()), 4).unwrap()
+    );
+    assert!(day06::read_markers(Cursor::new(&b"ab\xffcd"[..]), 2).is_err());
+    assert!(day06::read_markers(Cursor::new(&"a€".as_bytes()[..3]), 2).is_err());
+}
+
+#[test]
+fn framing() {
+    let stream = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+    let frames: Vec<_> = day06::frames(stream.chars(), MarkerKind::Packet).collect();
+    assert_eq!(7, frames.len());
+    assert_eq!(
+        Frame {
+            offset: 0,
+            kind: None,
+            marker: 0,
+            payload: 3,
+        },
+        frames[0]
+    );
+    // the first marker ends where part 1 says it does
+    assert_eq!(Some(MarkerKind::Packet), frames[1].kind);
+    assert_eq!(
+        Some(frames[1].payload_offset()),
+        day06::find_marker(stream, 4)
+    );
+    assert_eq!(
+        (23, 4, 3),
+        (frames[6].offset, frames[6].marker, frames[6].payload)
+    );
+    let total: usize = frames.iter().map(Frame::len).sum();
+    assert_eq!(stream.len(), total);
+
+    // frames tile the stream, and only the first has no marker
+    let input = input::real(6).unwrap();
+    let signal = input.trim_end();
+    let frames: Vec<_> = day06::frames(signal.chars(), MarkerKind::Message).collect();
+    for pair in frames.windows(2) {
+        assert_eq!(pair[0].offset + pair[0].len(), pair[1].offset);
+        assert_eq!(Some(MarkerKind::Message), pair[1].kind);
+    }
+    assert_eq!(day06::part2(signal), Ok(frames[1].payload_offset()));
+
+    // a stream that opens with a marker has no preamble
+    let frames: Vec<_> = day06::frames("abcabc".chars(), MarkerKind::Custom(3)).collect();
+    assert_eq!(
+        vec![0, 3],
+        frames.iter().map(|f| f.offset).collect::<Vec<_>>()
+    );
+    assert_eq!(0, day06::frames("".chars(), MarkerKind::Packet).count());
+}
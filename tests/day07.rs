@@ -1,146 +1,127 @@
-const INPUT: &str = include_str!("res/07.txt");
-const TOTAL_FS_SIZE: usize = 70_000_000;
-const REQUIRED_SPACE: usize = 30_000_000;
+use aoc_22::{
+    day07::{self, FileSystem},
+    input,
+};
 
-#[derive(Debug)]
-struct Dir<'a> {
-    _name: &'a str,
-    size: usize,
-    children: Vec<Box<Node<'a>>>,
-}
-
-impl<'a> Dir<'a> {
-    fn add_child(&mut self, node: Node<'a>) {
-        let size = node.size();
-        self.size += size;
-        self.children.push(Box::new(node));
-    }
-}
+#[test]
+fn day7() {
+    // parse the tree
+    let input = input::real(7).unwrap();
+    let fs = day07::parse(&input).unwrap();
 
-#[derive(Debug)]
-struct File<'a> {
-    _name: &'a str,
-    size: usize,
-}
+    // part 1
+    let part1 = day07::part1(&fs);
+    println!("Day 7, part 1: {part1}");
 
-#[derive(Debug)]
-enum Node<'a> {
-    Dir(Dir<'a>),
-    File(File<'a>),
+    // part 2
+    let part2 = day07::part2(&fs).unwrap();
+    println!("Day 7, part 2: {part2}");
 }
 
-impl Node<'_> {
-    fn size(&self) -> usize {
-        match self {
-            Node::Dir(d) => d.size,
-            Node::File(f) => f.size,
-        }
-    }
+fn example() -> FileSystem {
+    day07::parse(&input::named(7, input::EXAMPLE).unwrap()).unwrap()
 }
 
-#[derive(Debug)]
-enum Cmd<'a> {
-    Cd(&'a str),
-    Ls,
-    FileDescriptor(usize, &'a str),
-    DirName(&'a str),
-}
+#[test]
+fn paths() {
+    let fs = example();
+    let e = fs.lookup("/a/e").unwrap();
+    assert_eq!(584, fs.node(e).size);
+    assert_eq!("/a/e/", fs.path(e));
+    assert_eq!(Some(e), fs.lookup("/a/e/"));
+    assert_eq!(Some(FileSystem::ROOT), fs.lookup("/"));
+    assert_eq!(None, fs.lookup("/a/x"));
+    assert_eq!(None, fs.lookup("a/e"));
+    let i = fs.lookup("/a/e/i").unwrap();
+    assert_eq!("/a/e/i", fs.path(i));
+    assert!(!fs.node(i).is_dir());
 
-impl<'a> Cmd<'a> {
-    fn parse(line: &'a str) -> Self {
-        let tokens: Vec<_> = line.split_ascii_whitespace().collect();
-        let cmd = tokens[1];
-        match tokens[0] {
-            "$" => match cmd {
-                "ls" => return Cmd::Ls,
-                "cd" => {
-                    let dir = tokens[2];
-                    return Cmd::Cd(dir);
-                }
-                _ => panic!(),
-            },
-            _ => match tokens[0].parse::<usize>() {
-                Ok(size) => Cmd::FileDescriptor(size, tokens[1]),
-                Err(_) => Cmd::DirName(tokens[1]),
-            },
-        }
-    }
+    let paths: Vec<_> = fs.entries().map(|(path, _)| path).take(5).collect();
+    assert_eq!(vec!["/", "/a/", "/a/e/", "/a/e/i", "/a/f"], paths);
+    assert_eq!(14, fs.entries().count());
 }
 
-impl<'a> Node<'a> {
-    fn parse(lines: &mut impl Iterator<Item = &'a str>, name: &'a str) -> Node<'a> {
-        let mut dir = Dir {
-            _name: name,
-            size: 0,
-            children: Vec::new(),
-        };
-        while let Some(line) = lines.next() {
-            match Cmd::parse(line) {
-                Cmd::Cd("..") => return Node::Dir(dir),
-                Cmd::Cd(child) => {
-                    let child = Node::parse(lines, child);
-                    dir.add_child(child);
-                }
-                Cmd::FileDescriptor(size, name) => {
-                    let file = Node::File(File { size, _name: name });
-                    dir.add_child(file);
-                }
-                _ => {}
-            }
-        }
-        Node::Dir(dir)
-    }
+#[test]
+fn sizes() {
+    let fs = example();
+    let du = fs.du(FileSystem::ROOT);
+    assert_eq!(
+        vec![
+            ("/a/e/".to_string(), 584),
+            ("/a/".to_string(), 94853),
+            ("/d/".to_string(), 24933642),
+            ("/".to_string(), 48381165)
+        ],
+        du
+    );
+    let small: Vec<_> = fs
+        .find_dirs(|size| size <= 100_000)
+        .iter()
+        .map(|entry| fs.path(entry.id))
+        .collect();
+    assert_eq!(vec!["/a/", "/a/e/"], small);
+    let logs: Vec<_> = fs
+        .find_name("*.l?g")
+        .iter()
+        .map(|entry| fs.path(entry.id))
+        .collect();
+    assert_eq!(vec!["/d/d.log"], logs);
+    // every one-letter name: a, d, e, f, g, i, j and k
+    assert_eq!(8, fs.find_name("?").len());
+    assert!(day07::glob("*", "") && !day07::glob("?", ""));
 
-    fn space_available(&self) -> usize {
-        TOTAL_FS_SIZE - self.size()
-    }
-}
+    assert_eq!("584B", day07::human_size(584));
+    assert_eq!("93K", day07::human_size(94853));
+    assert_eq!("2.5K", day07::human_size(2557));
+    // rounded up like du, even when that reaches the next unit
+    assert_eq!("47M", day07::human_size(48381165));
+    assert_eq!("1.0K", day07::human_size(1024));
+    assert_eq!("1.1K", day07::human_size(1025));
+    assert_eq!("10K", day07::human_size(10 * 1024));
+    assert_eq!("1.0M", day07::human_size(1024 * 1024 - 1));
 
-fn sum_under_threshold(node: &Node, threshold: usize) -> usize {
-    match node {
-        Node::File(_) => 0,
-        Node::Dir(dir) => {
-            let mut acc = if dir.size <= threshold { dir.size } else { 0 };
-            for child in &dir.children {
-                acc += sum_under_threshold(child.as_ref(), threshold);
-            }
-            return acc;
-        }
-    }
+    // the files have to fit on the disk before any space can be freed
+    let fs = day07::parse("$ cd /\n$ ls\n70000001 big").unwrap();
+    let error = day07::part2(&fs).unwrap_err();
+    assert_eq!(70_000_001, error.used);
 }
 
-fn find_deletion_candidates<'a, 'b>(
-    node: &'b Node<'a>,
-    space_available: usize,
-    candidates: &mut Vec<&'b Dir<'a>>,
-) {
-    match node {
-        Node::File(_) => {}
-        Node::Dir(d) => {
-            if space_available + d.size > REQUIRED_SPACE {
-                candidates.push(d);
-            }
-            for child in &d.children {
-                find_deletion_candidates(child, space_available, candidates)
-            }
-        }
-    }
+#[test]
+fn tree() {
+    let fs = example();
+    let expected = "\
+/ (47M)
+├── a/ (93K)
+│   ├── e/ (584B)
+│   │   └── i (584B)
+│   ├── f (29K)
+│   ├── g (2.5K)
+│   └── h.lst (62K)
+├── b.txt (15M)
+├── c.dat (8.2M)
+└── d/ (24M)
+    ├── d.ext (5.4M)
+    ├── d.log (7.7M)
+    ├── j (3.9M)
+    └── k (6.9M)";
+    assert_eq!(expected, fs.tree(FileSystem::ROOT));
+    assert_eq!(
+        "e/ (584B)\n└── i (584B)",
+        fs.tree(fs.lookup("/a/e").unwrap())
+    );
 }
 
 #[test]
-fn day7() {
-    // parse the tree
-    let mut lines = INPUT.lines();
-    let tree = Node::parse(&mut lines, "/");
+fn sessions() {
+    // listing twice and going back to the root change nothing
+    let input =
+        "$ cd /\n$ ls\n10 x\ndir a\n$ cd a\n$ ls\n5 y\n$ cd /\n$ ls\n10 x\n$ cd a\n$ ls\n7 y";
+    let fs = day07::parse(input).unwrap();
+    assert_eq!(17, fs.node(FileSystem::ROOT).size);
+    assert_eq!(7, fs.node(fs.lookup("/a").unwrap()).size);
 
-    // part 1
-    let part1 = sum_under_threshold(&tree, 100_000);
-    println!("Day 7, part 1: {part1}");
-
-    // part 2
-    let mut candidates = Vec::new();
-    let space_available = tree.space_available();
-    find_deletion_candidates(&tree, space_available, &mut candidates);
-    let part2 = candidates.iter().map(|dir| dir.size).min().unwrap();
-    println!("Day 7, part 2: {part2}");
+    let error = day07::parse("$ cd ..").unwrap_err();
+    assert_eq!("a directory below /", error.expected);
+    let error = day07::parse("dir a\n5 a").unwrap_err();
+    assert_eq!((2, 3), (error.line, error.column));
 }
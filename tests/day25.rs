@@ -1,52 +1,29 @@
-const INPUT: &str = include_str!("res/25.txt");
-
-fn from_snafu(input: &str) -> i64 {
-    input
-        .chars()
-        .rev()
-        .map(|c| match c {
-            '=' => -2,
-            '-' => -1,
-            c => c.to_digit(10).unwrap() as i64,
-        })
-        .enumerate()
-        .map(|(i, c)| 5i64.pow(i as u32) * c)
-        .sum()
-}
-
-fn to_snafu(mut value: i64) -> String {
-    let mut digits = Vec::new();
-    while value > 0 {
-        // remainder mod 5
-        let remainder = value % 5;
-        let adjusted = match remainder {
-            4 => -1,
-            3 => -2,
-            x => x,
-        };
-        digits.push(adjusted);
-        value -= adjusted;
-        value /= 5;
-    }
-    // println!("{digits:?}");
-    digits
-        .iter()
-        .rev()
-        .map(|d| match d {
-            -2 => '=',
-            -1 => '-',
-            0 => '0',
-            1 => '1',
-            2 => '2',
-            _ => unreachable!(),
-        })
-        .collect()
-}
+use aoc_22::{day25, input};
 
 #[test]
 fn part1() {
-    let value = INPUT.lines().map(from_snafu).sum();
-    let ans = to_snafu(value);
+    let input = input::real(25).unwrap();
+    let numbers = day25::parse(&input).unwrap();
+    let ans = day25::part1(&numbers);
     println!("Day 25, part 1: {ans}");
     assert_eq!("2-21=02=1-121-2-11-0", ans);
 }
+
+#[test]
+fn out_of_range() {
+    // 5^27 fits in an i64, but 5^28 doesn't
+    let fits = format!("1{}", "0".repeat(27));
+    assert_eq!(Some(5i64.pow(27)), day25::from_snafu(&fits));
+    let error = day25::parse(&format!("1=\n{fits}0")).unwrap_err();
+    assert_eq!(
+        (2, 29, "0"),
+        (error.line, error.column, error.token.as_str())
+    );
+    assert_eq!("a number that fits in 64 bits", error.expected);
+    assert_eq!(None, day25::from_snafu(&format!("{fits}0")));
+
+    // leading zeros don't count towards the size
+    let padded = format!("{}1=", "0".repeat(40));
+    assert_eq!(vec![3], day25::parse(&padded).unwrap());
+    assert_eq!(None, day25::from_snafu("12x"));
+}
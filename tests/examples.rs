@@ -0,0 +1,49 @@
+use std::path::Path;
+
+use aoc_22::{
+    day10, day15, input,
+    manifest::{self, Manifest, Status},
+};
+
+/// Every example answer in the manifest, checked in one go so that a failure
+/// lists all of the days that broke
+#[test]
+fn examples() {
+    let dir = Path::new(input::DEFAULT_DIR);
+    let mut manifest = Manifest::load(&dir.join(manifest::FILE_NAME)).unwrap();
+    manifest.entries.retain(|entry| entry.file.contains("ex"));
+    assert!(!manifest.entries.is_empty());
+
+    let failures: Vec<_> = manifest::verify(&manifest, dir)
+        .into_iter()
+        .filter(|check| check.status != Status::Pass)
+        .map(|check| {
+            let entry = check.entry;
+            format!(
+                "{} (day {}, part {}): {:?}",
+                entry.file, entry.day, entry.part, check.status
+            )
+        })
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn day15() {
+    let input = input::named(15, input::EXAMPLE).unwrap();
+    let sensors = day15::parse(&input).unwrap();
+    assert_eq!(26, day15::part1(&sensors, 10));
+    assert_eq!(Ok(56000011), day15::part2(&sensors, 20));
+    // the example's one gap is outside a smaller search area
+    let error = day15::part2(&sensors, 10).unwrap_err();
+    assert_eq!("no uncovered position within 0..=10", error.to_string());
+}
+
+/// The first day 10 example finishes long before the cycles that get checked
+#[test]
+fn day10_short_program() {
+    let input = input::named(10, "ex1").unwrap();
+    let history = day10::parse(&input).unwrap();
+    assert_eq!(0, day10::part1(&history));
+    assert_eq!(6, day10::part2(&history).lines().count());
+}
@@ -0,0 +1,83 @@
+use aoc_22::{
+    day14::{self, Cave},
+    grid::{Bounds, Coord, Dense, Grid, Sparse},
+    input,
+};
+
+fn digits(input: &str) -> Dense<u32> {
+    Dense::parse(8, input, |_, c| c.to_digit(10).ok_or("a digit")).unwrap()
+}
+
+#[test]
+fn dense_coordinates() {
+    let grid = digits("123\n456");
+    assert_eq!((3, 2), (grid.width(), grid.height()));
+    // x is the column and y is the row
+    assert_eq!(Some(&6), grid.get(Coord::new(2, 1)));
+    assert_eq!(None, grid.get(Coord::new(1, 2)));
+    assert_eq!(None, grid.get(Coord::new(-1, 0)));
+    assert_eq!(
+        Some(Bounds::new(Coord::ORIGIN, Coord::new(2, 1))),
+        grid.bounds()
+    );
+}
+
+#[test]
+fn neighbours() {
+    let grid = digits("123\n456\n789");
+    let four: Vec<_> = grid.neighbors4(Coord::ORIGIN).map(|(_, v)| *v).collect();
+    assert_eq!(vec![2, 4], four);
+    // the same order as every other point: +x, -x, +y, -y
+    let four: Vec<_> = grid.neighbors4(Coord::new(1, 1)).map(|(_, v)| *v).collect();
+    assert_eq!(vec![6, 4, 8, 2], four);
+    assert_eq!(8, grid.neighbors8(Coord::new(1, 1)).count());
+    let ray: Vec<_> = grid
+        .ray(Coord::new(0, 2), Coord::new(1, -1))
+        .map(|(_, v)| *v)
+        .collect();
+    assert_eq!(vec![5, 3], ray);
+}
+
+#[test]
+fn parse_errors() {
+    let error = Dense::parse(8, "123\n45", |_, c| c.to_digit(10).ok_or("a digit")).unwrap_err();
+    assert_eq!(
+        (2, 3, "a row of 3 cells"),
+        (error.line, error.column, error.expected.as_str())
+    );
+    let error = Dense::parse(8, "123\n4x6", |_, c| c.to_digit(10).ok_or("a digit")).unwrap_err();
+    assert_eq!(
+        (2, 2, "x"),
+        (error.line, error.column, error.token.as_str())
+    );
+}
+
+#[test]
+fn sparse_rendering() {
+    let grid: Sparse<char> = digits("100\n001")
+        .iter()
+        .filter(|(_, v)| **v == 1)
+        .map(|(at, _)| (at, '#'))
+        .collect();
+    assert_eq!(2, grid.len());
+    assert_eq!("#..\n..#", grid.render(|_, c| c.copied().unwrap_or('.')));
+}
+
+#[test]
+fn render_cave() {
+    let input = input::named(14, input::EXAMPLE).unwrap();
+    let cave = Cave::new(&input).unwrap();
+    assert_eq!(24, day14::part1(&cave));
+    let expected = "\
+......+...
+..........
+..........
+..........
+....#...##
+....#...#.
+..###...#.
+........#.
+........#.
+#########.";
+    assert_eq!(expected, cave.to_string());
+}
@@ -0,0 +1,33 @@
+use aoc_22::{
+    error::InputError,
+    input::{self, Source},
+};
+
+#[test]
+fn resolve_by_name() {
+    let source = Source::Dir("tests/res".into());
+    let resolve = |day, name| source.resolve(day, name).unwrap();
+    assert_eq!(resolve(8, input::REAL), source_path("08.txt"));
+    assert_eq!(resolve(8, input::EXAMPLE), source_path("08ex.txt"));
+    // days with several examples fall back to the first one
+    assert_eq!(resolve(9, input::EXAMPLE), source_path("09ex1.txt"));
+    assert_eq!(resolve(9, "ex2"), source_path("09ex2.txt"));
+}
+
+#[test]
+fn explicit_file_ignores_day() {
+    let source = Source::from_arg("tests/res/02ex.txt");
+    assert_eq!("A Y\nB X\nC Z", source.load(25, input::REAL).unwrap());
+    assert_eq!(Source::Stdin, Source::from_arg("-"));
+}
+
+#[test]
+fn missing_input() {
+    let source = Source::Dir("tests/res".into());
+    let error = source.load(16, input::REAL).unwrap_err();
+    assert!(matches!(error, InputError::Missing(_)));
+}
+
+fn source_path(file: &str) -> std::path::PathBuf {
+    std::path::Path::new("tests/res").join(file)
+}
@@ -0,0 +1,97 @@
+use aoc_22::interval::{Interval, IntervalSet};
+
+fn set(intervals: &[(i32, i32)]) -> IntervalSet<i32> {
+    intervals
+        .iter()
+        .map(|&(start, end)| Interval::new(start, end).unwrap())
+        .collect()
+}
+
+#[test]
+fn intervals() {
+    let a = Interval::new(2, 6).unwrap();
+    let b = Interval::new(4, 8).unwrap();
+    assert_eq!(None, Interval::new(3, 2));
+    assert_eq!(Some(5), a.len());
+    assert!(a.overlaps(&b) && !a.covers(&b));
+    assert!(a.covers(&Interval::point(6)));
+    assert_eq!(Interval::new(4, 6), a.intersection(&b));
+    // 7-8 starts right after 2-6 ends
+    assert!(a.touches(&Interval::new(7, 8).unwrap()));
+    assert!(!a.touches(&Interval::new(8, 8).unwrap()));
+
+    // the gap between these is wider than i32 can hold
+    let (low, high) = (Interval::point(i32::MIN), Interval::point(i32::MAX));
+    assert!(!low.touches(&Interval::point(10)) && !high.touches(&low));
+    assert!(high.touches(&Interval::point(i32::MAX - 1)));
+}
+
+#[test]
+fn insert_coalesces() {
+    let mut s = IntervalSet::new();
+    s.insert(Interval::new(10, 12).unwrap());
+    s.insert(Interval::new(1, 3).unwrap());
+    s.insert(Interval::new(6, 6).unwrap());
+    assert_eq!("{1-3, 6-6, 10-12}", s.to_string());
+    s.insert(Interval::new(4, 9).unwrap());
+    assert_eq!("{1-12}", s.to_string());
+    assert_eq!(set(&[(1, 12)]), s);
+    assert_eq!(set(&[(5, 9), (1, 3), (2, 4)]), set(&[(1, 9)]));
+    assert_eq!(Some(12), s.total_len());
+    assert_eq!(Some(0), IntervalSet::<i32>::new().total_len());
+}
+
+#[test]
+fn algebra() {
+    let a = set(&[(0, 4), (8, 12), (20, 20)]);
+    let b = set(&[(3, 9), (12, 15)]);
+    assert_eq!(set(&[(0, 15), (20, 20)]), a.union(&b));
+    assert_eq!(set(&[(3, 4), (8, 9), (12, 12)]), a.intersection(&b));
+    assert_eq!(set(&[(0, 2), (10, 11), (20, 20)]), a.difference(&b));
+    assert_eq!(set(&[(5, 7), (13, 15)]), b.difference(&a));
+    assert_eq!(
+        set(&[(-2, -1), (5, 7), (13, 19)]),
+        a.gaps(Interval::new(-2, 19).unwrap())
+    );
+    assert!(a.contains(10) && !a.contains(5));
+    assert!(a.covers(&Interval::new(8, 12).unwrap()));
+    assert!(!a.covers(&Interval::new(4, 8).unwrap()));
+    // sets reaching both ends of the range
+    let wide = set(&[(i32::MIN, -5), (5, i32::MAX), (i32::MIN, i32::MIN)]);
+    assert_eq!(2, wide.len());
+    let mut grown = wide.clone();
+    grown.insert(Interval::point(0));
+    assert_eq!(3, grown.len());
+    assert_eq!(
+        set(&[(-4, 4)]),
+        wide.gaps(Interval::new(i32::MIN, i32::MAX).unwrap())
+    );
+    assert_eq!(
+        set(&[(i32::MIN, -6), (6, i32::MAX)]),
+        wide.difference(&set(&[(-5, 5)]))
+    );
+}
+
+#[test]
+fn unsigned_endpoints() {
+    let s: IntervalSet<u32> = [Interval::new(0, 0), Interval::new(1, u32::MAX)]
+        .into_iter()
+        .flatten()
+        .collect();
+    assert_eq!(1, s.len());
+    assert!(s.gaps(Interval::new(0, 5).unwrap()).is_empty());
+
+    // every u32 is one more than u32 can count
+    let full = Interval::new(0, u32::MAX).unwrap();
+    assert_eq!(None, full.len());
+    assert_eq!(None, s.total_len());
+    assert_eq!(Some(u32::MAX), Interval::new(1, u32::MAX).unwrap().len());
+    assert_eq!(None, Interval::new(i8::MIN, i8::MAX).unwrap().len());
+    // the intervals don't overlap, so only the full range is too big to count
+    let halves = IntervalSet::from_iter([
+        Interval::new(0, u32::MAX / 2).unwrap(),
+        Interval::new(u32::MAX / 2 + 2, u32::MAX).unwrap(),
+    ]);
+    assert_eq!(2, halves.len());
+    assert_eq!(Some(u32::MAX), halves.total_len());
+}
@@ -0,0 +1,29 @@
+use aoc_22::{
+    error::ManifestError,
+    manifest::{self, Manifest, Status},
+    solution::Part,
+};
+
+#[test]
+fn parse_entries() {
+    let manifest =
+        Manifest::parse("# comment\n\n02.txt  2  1  11386\n10.txt 10 2 #.\\n.#\n").unwrap();
+    assert_eq!(2, manifest.entries.len());
+    assert_eq!(Part::One, manifest.entries[0].part);
+    assert_eq!("#.\n.#", manifest.entries[1].answer);
+
+    let error = Manifest::parse("02.txt 2 3 11386").unwrap_err();
+    assert!(matches!(error, ManifestError::Syntax { line: 1, .. }));
+}
+
+#[test]
+fn pass_fail_and_missing() {
+    let manifest = Manifest::parse("02.txt 2 1 11386\n02.txt 2 2 1\n16.txt 16 1 1651").unwrap();
+    let statuses: Vec<_> = manifest::verify(&manifest, "tests/res".as_ref())
+        .into_iter()
+        .map(|check| check.status)
+        .collect();
+    assert_eq!(Status::Pass, statuses[0]);
+    assert_eq!(Status::Fail("got 13600".into()), statuses[1]);
+    assert!(matches!(statuses[2], Status::Missing(_)));
+}
@@ -0,0 +1,62 @@
+use aoc_22::{day02, day09, day11, day13, day25, input};
+
+#[test]
+fn points_at_the_offending_token() {
+    let error = day09::parse("R 4\nU 4\nX 3").unwrap_err();
+    assert_eq!((9, 3, 1), (error.day, error.line, error.column));
+    assert_eq!("X", error.token);
+    assert_eq!("a direction (U, D, L or R)", error.expected);
+
+    let error = day09::parse("R 4\nU four").unwrap_err();
+    assert_eq!(
+        (2, 3, "four"),
+        (error.line, error.column, error.token.as_str())
+    );
+}
+
+#[test]
+fn reports_lines_that_end_early() {
+    let error = day02::parse("A Y\nB").unwrap_err();
+    assert_eq!((2, 2, ""), (error.line, error.column, error.token.as_str()));
+    assert_eq!(
+        "day 2, line 2, column 2: expected a response (X, Y or Z), found end of line",
+        error.to_string()
+    );
+}
+
+#[test]
+fn nested_grammar() {
+    let error = day13::parse("[1,[2,3]]\n[1,[2;3]]").unwrap_err();
+    assert_eq!(
+        (2, 6, ";"),
+        (error.line, error.column, error.token.as_str())
+    );
+    assert_eq!("',' or ']'", error.expected);
+
+    // a pair with only one packet
+    let error = day13::parse("[1]\n[2]\n\n[3]").unwrap_err();
+    assert_eq!(4, error.line);
+}
+
+#[test]
+fn line_numbers_span_blocks() {
+    let input = input::named(11, input::EXAMPLE).unwrap();
+    let broken = input.replace("new = old + 6", "new = old % 6");
+    let error = day11::parse(&broken).unwrap_err();
+    assert_eq!(
+        (10, 24, "%"),
+        (error.line, error.column, error.token.as_str())
+    );
+}
+
+#[test]
+fn diagnostic() {
+    let error = day25::parse("1=-0-2\n12a11").unwrap_err();
+    let expected = "\
+expected a SNAFU digit (=, -, 0, 1 or 2), found \"a\"
+ --> day 25, line 2, column 3
+  |
+2 | 12a11
+  |   ^";
+    assert_eq!(expected, error.diagnostic());
+}
@@ -0,0 +1,43 @@
+use aoc_22::point::{Point2, Point3};
+
+#[test]
+fn arithmetic() {
+    let a = Point2::new(3, -2);
+    let b = Point2::new(1, 4);
+    assert_eq!(Point2::new(4, 2), a + b);
+    assert_eq!(Point2::new(2, -6), a - b);
+    assert_eq!(Point2::new(-3, 2), -a);
+    assert_eq!(Point2::new(9, -6), a * 3);
+
+    let mut c = Point3::new(1, 2, 3);
+    c += Point3::UNIT_Z;
+    c -= Point3::UNIT_X;
+    c *= 2;
+    assert_eq!(Point3::new(0, 4, 8), c);
+}
+
+#[test]
+fn distances() {
+    let a = Point2::new(0i64, 0);
+    let b = Point2::new(3, -4);
+    assert_eq!(7, a.manhattan(b));
+    assert_eq!(4, a.chebyshev(b));
+    assert_eq!(Point2::new(1, -1), (b - a).signum());
+    assert_eq!(12, Point3::new(1, 2, 3).manhattan(Point3::new(-1, -2, -3)));
+}
+
+#[test]
+fn neighbours() {
+    let origin = Point2::<i32>::ORIGIN;
+    assert!(Point2::NEIGHBORS_4
+        .iter()
+        .all(|&n| origin.manhattan(n) == 1));
+    assert!(Point2::NEIGHBORS_8
+        .iter()
+        .all(|&n| origin.chebyshev(n) == 1));
+    let sum = Point3::<i32>::NEIGHBORS_6
+        .iter()
+        .fold(Point3::ORIGIN, |acc, &n| acc + n);
+    assert_eq!(Point3::ORIGIN, sum);
+    assert_eq!((3, 4), Point2::from((3, 4)).into());
+}
@@ -0,0 +1,37 @@
+use aoc_22::{error::RunError, input, registry, solution::Part};
+
+#[test]
+fn days_are_ordered() {
+    let numbers: Vec<_> = registry::DAYS.iter().map(|day| day.number).collect();
+    let mut sorted = numbers.clone();
+    sorted.sort_unstable();
+    sorted.dedup();
+    assert_eq!(sorted, numbers);
+}
+
+#[test]
+fn run_by_number() {
+    let day = registry::find(10).unwrap();
+    let run = day.run(&input::real(10).unwrap(), &Part::BOTH).unwrap();
+    assert_eq!("15680", run.answers[0].value);
+    // the CRT is rendered as one line per scan line
+    assert_eq!(6, run.answers[1].value.lines().count());
+    assert!(registry::find(19).is_none());
+}
+
+#[test]
+fn solver_errors() {
+    let day = registry::find(3).unwrap();
+    let error = day.run("abab\n", &Part::BOTH).unwrap_err();
+    assert!(matches!(
+        error,
+        RunError::Solve {
+            part: Part::One,
+            ..
+        }
+    ));
+    assert_eq!(
+        "day 3, part 1: the rucksack on line 1 has 2 items in common (ab)",
+        error.to_string()
+    );
+}